@@ -54,7 +54,8 @@ fn bench_network_tick_all_to_all(bn: &mut Bencher) {
         a_neg: 1.0,
         continuous: false,
         scale: false,
-        delay: 1,
+        delay: 1.0,
+        dendritic: 0.0,
       });
       network.add_synapse(synapse, n, m).unwrap();
     }
@@ -136,7 +137,8 @@ fn bench_network_tick_limited(bn: &mut Bencher) {
         a_neg: 1.0,
         continuous: false,
         scale: false,
-        delay: 1,
+        delay: 1.0,
+        dendritic: 0.0,
       });
       network.add_synapse(synapse, n, m).unwrap();
       i += 1;
@@ -63,7 +63,8 @@ fn main() {
         a_neg: 1.0,
         continuous: false,
         scale: false,
-        delay: 1,
+        delay: 1.0,
+        dendritic: 0.0,
       });
       network.add_synapse(synapse, n, m).unwrap();
     }
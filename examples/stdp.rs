@@ -9,9 +9,11 @@ use std::path::Path;
 use std::fs;
 use rand::{Rng, SeedableRng, StdRng};
 
+use neural::Float;
 use neural::Network;
 use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
 use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::homeostasis::HomeostasisConfig;
 use neural::traces::ExpTrace;
 
 fn main() {
@@ -94,9 +96,9 @@ fn main() {
       };
 
       let delay = if n < excitatory_count {
-        rng.gen_range::<usize>(1, max_delay)
+        rng.gen_range::<usize>(1, max_delay) as Float
       } else {
-        1
+        1.0
       };
 
       let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
@@ -112,12 +114,20 @@ fn main() {
         continuous: false,
         scale: false,
         delay: delay,
+        dendritic: 0.0,
       });
       network.add_synapse(synapse, n, m).unwrap();
       i += 1;
     }
   }
 
+  // keep excitatory weights from drifting to their bounds
+  let excitatory_ids: Vec<usize> = (0..excitatory_count).collect();
+  network.add_homeostasis(&excitatory_ids, HomeostasisConfig{
+    target_rate: 0.01,
+    ..Default::default()
+  }).unwrap();
+
   let mut vinp = vec![0.0; 1000];
   let mut voup = vec![0.0; 1000];
 
@@ -5,6 +5,7 @@ extern crate neural;
 use std::mem::transmute;
 
 use neural::Network;
+use neural::network::NeuralError;
 use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
 use neural::sym::{SymSynapse, SymConfig};
 use neural::Float;
@@ -54,6 +55,13 @@ pub extern fn DumpWeights(network: *mut SymNetwork, weights_ptr: *mut Float) {
   _network.dump_weights(weights);
 }
 
+#[no_mangle]
+pub extern fn LoadWeights(network: *mut SymNetwork, weights_ptr: *const Float, count: usize) {
+  let mut _network = unsafe { &mut *network };
+  let weights = unsafe { std::slice::from_raw_parts(weights_ptr, count) };
+  _network.load_weights(weights);
+}
+
 #[no_mangle]
 pub extern fn AddNeuron(network: *mut SymNetwork, config: IzhikevichConfig) -> usize {
   let mut _network = unsafe { &mut *network };
@@ -62,12 +70,26 @@ pub extern fn AddNeuron(network: *mut SymNetwork, config: IzhikevichConfig) -> u
   neuronId
 }
 
+// Status codes returned across the FFI: 0 on success, otherwise one more
+// than the `NeuralError` discriminant.
+fn status(result: Result<(), NeuralError>) -> i32 {
+  match result {
+    Ok(()) => 0,
+    Err(err) => err as i32 + 1,
+  }
+}
+
+// Since 0.2.0 `SymConfig.delay` is a float rather than a usize, `dendritic`
+// follows `max`, and the synapse id is written to `synapse_id_ptr` while a
+// status is returned. Config fields added later go last to keep the C
+// layout of the ones before.
 #[no_mangle]
-pub extern fn AddSynapse(network: *mut SymNetwork, sendr_id: usize, recvr_id: usize, config: SymConfig) -> usize {
+pub extern fn AddSynapse(network: *mut SymNetwork, sendr_id: usize, recvr_id: usize, config: SymConfig, synapse_id_ptr: *mut usize) -> i32 {
   let mut _network = unsafe { &mut *network };
   let synapse = SymSynapse::new(config);
-  let synapseId = _network.add_synapse(synapse, sendr_id, recvr_id).unwrap();
-  synapseId
+  status(_network.add_synapse(synapse, sendr_id, recvr_id).map(|synapseId| {
+    unsafe { *synapse_id_ptr = synapseId };
+  }))
 }
 
 #[no_mangle]
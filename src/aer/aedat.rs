@@ -0,0 +1,60 @@
+use std::io;
+use std::io::Read;
+use std::fs::File;
+use std::path::Path;
+
+use aer::event::AerEvent;
+
+// Reads jAER AEDAT 1.0 and 2.0 recordings of a DVS128. The header is a
+// run of lines starting with '#', version 2.0 stores big-endian 32-bit
+// addresses and timestamps and version 1.0, or a file without a version
+// line, 16-bit addresses. Addresses decode as jAER does: x from bits 1-7
+// mirrored, y from bits 8-14 and an ON event when bit 0 is clear.
+pub fn read_aedat<R: Read>(mut reader: R) -> io::Result<Vec<AerEvent>> {
+  let mut data = Vec::new();
+  try!(reader.read_to_end(&mut data));
+
+  let mut start = 0;
+  let mut address_bytes = 2;
+  while start < data.len() && data[start] == b'#' {
+    let end = match data[start..].iter().position(|&b| b == b'\n') {
+      Some(i) => start + i + 1,
+      None => data.len(),
+    };
+
+    let line = &data[start..end];
+    if line.starts_with(b"#!AER-DAT") {
+      if line.starts_with(b"#!AER-DAT2.") {
+        address_bytes = 4;
+      } else if !line.starts_with(b"#!AER-DAT1.") {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "unsupported AEDAT version"));
+      }
+    }
+    start = end;
+  }
+
+  let size = address_bytes + 4;
+  let body = &data[start..];
+  if body.len() % size != 0 {
+    return Err(io::Error::new(io::ErrorKind::InvalidData, "truncated AEDAT event"));
+  }
+
+  Ok(body.chunks(size).map(|chunk| {
+    let address = big_endian(&chunk[..address_bytes]);
+    let timestamp = big_endian(&chunk[address_bytes..]);
+    AerEvent{
+      x: 127 - ((address >> 1) & 0x7f) as u16,
+      y: ((address >> 8) & 0x7f) as u16,
+      polarity: address & 1 == 0,
+      timestamp: timestamp as u64,
+    }
+  }).collect())
+}
+
+pub fn open_aedat<P: AsRef<Path>>(path: P) -> io::Result<Vec<AerEvent>> {
+  read_aedat(try!(File::open(path)))
+}
+
+fn big_endian(bytes: &[u8]) -> u32 {
+  bytes.iter().fold(0, |v, &b| (v << 8) | b as u32)
+}
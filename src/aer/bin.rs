@@ -0,0 +1,48 @@
+use std::io;
+use std::io::Read;
+use std::fs::File;
+use std::path::Path;
+
+use aer::event::AerEvent;
+
+// Events are 40 bits: x, y, then the polarity in the top bit ahead of a
+// 23-bit timestamp.
+const EVENT_BYTES: usize = 5;
+
+// A y of 240 marks a timestamp overflow, after which timestamps continue
+// from 2^13 microseconds later.
+const OVERFLOW_Y: u8 = 240;
+const OVERFLOW_US: u64 = 1 << 13;
+
+// Reads the raw binary event format of the N-MNIST and N-Caltech101
+// datasets of Orchard et al. (2015).
+pub fn read_bin<R: Read>(mut reader: R) -> io::Result<Vec<AerEvent>> {
+  let mut data = Vec::new();
+  try!(reader.read_to_end(&mut data));
+
+  if data.len() % EVENT_BYTES != 0 {
+    return Err(io::Error::new(io::ErrorKind::InvalidData, "truncated event"));
+  }
+
+  let mut events = Vec::with_capacity(data.len() / EVENT_BYTES);
+  let mut overflow = 0;
+  for chunk in data.chunks(EVENT_BYTES) {
+    if chunk[1] == OVERFLOW_Y {
+      overflow += OVERFLOW_US;
+      continue;
+    }
+
+    let timestamp = ((chunk[2] as u64 & 0x7f) << 16) | ((chunk[3] as u64) << 8) | chunk[4] as u64;
+    events.push(AerEvent{
+      x: chunk[0] as u16,
+      y: chunk[1] as u16,
+      polarity: chunk[2] & 0x80 != 0,
+      timestamp: timestamp + overflow,
+    });
+  }
+  Ok(events)
+}
+
+pub fn open_bin<P: AsRef<Path>>(path: P) -> io::Result<Vec<AerEvent>> {
+  read_bin(try!(File::open(path)))
+}
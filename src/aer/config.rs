@@ -0,0 +1,41 @@
+use Float;
+use std::default::Default;
+
+// Layout of the input neurons driven by an address-event sensor. Defaults
+// fit the 34x34 N-MNIST recordings with 1ms ticks.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AerConfig {
+  // sensor size, events outside it are dropped
+  pub width: usize,
+  pub height: usize,
+
+  // whether ON and OFF events drive separate neurons, ON events taking
+  // the second `width * height` block, or both the same neuron
+  pub split_polarity: bool,
+
+  // microseconds per network tick
+  pub tick_us: Float,
+}
+
+impl Default for AerConfig {
+  fn default() -> AerConfig {
+    AerConfig{
+      width: 34,
+      height: 34,
+      split_polarity: true,
+      tick_us: 1000.0,
+    }
+  }
+}
+
+impl AerConfig {
+  // The 128x128 DVS128 camera.
+  pub fn dvs128() -> AerConfig {
+    AerConfig{
+      width: 128,
+      height: 128,
+      ..Default::default()
+    }
+  }
+}
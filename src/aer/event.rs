@@ -0,0 +1,11 @@
+// A sensor event, with the timestamp in microseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AerEvent {
+  pub x: u16,
+  pub y: u16,
+
+  // true for ON (brightness increase) events
+  pub polarity: bool,
+
+  pub timestamp: u64,
+}
@@ -0,0 +1,11 @@
+pub use self::config::AerConfig;
+pub use self::event::AerEvent;
+pub use self::aedat::{read_aedat, open_aedat};
+pub use self::bin::{read_bin, open_bin};
+pub use self::stream::AerStream;
+
+pub mod config;
+pub mod event;
+pub mod aedat;
+pub mod bin;
+pub mod stream;
@@ -0,0 +1,107 @@
+use Float;
+use aer::config::AerConfig;
+use aer::event::AerEvent;
+use encoding::event::Event;
+
+// Replays sensor events a window of ticks at a time, as events on input
+// channels ready for `encoding::fill`. The first event falls on tick 0.
+pub struct AerStream {
+  width: usize,
+  height: usize,
+  split_polarity: bool,
+  tick_us: Float,
+
+  events: Vec<AerEvent>,
+  next: usize,
+
+  start: u64,
+  tick: usize,
+}
+
+impl AerStream {
+  pub fn new(config: AerConfig, events: Vec<AerEvent>) -> AerStream {
+    let mut events = events;
+    events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    let start = events.first().map_or(0, |e| e.timestamp);
+
+    AerStream{
+      width: config.width,
+      height: config.height,
+      split_polarity: config.split_polarity,
+      tick_us: config.tick_us,
+      events: events,
+      next: 0,
+      start: start,
+      tick: 0,
+    }
+  }
+
+  // Input channels driven by the sensor.
+  pub fn channels(&self) -> usize {
+    if self.split_polarity {
+      2 * self.width * self.height
+    } else {
+      self.width * self.height
+    }
+  }
+
+  // Input channel of an event, `None` when it falls outside the sensor.
+  pub fn channel(&self, event: &AerEvent) -> Option<usize> {
+    let (x, y) = (event.x as usize, event.y as usize);
+    if x >= self.width || y >= self.height {
+      return None;
+    }
+
+    let id = y * self.width + x;
+    if self.split_polarity && event.polarity {
+      Some(id + self.width * self.height)
+    } else {
+      Some(id)
+    }
+  }
+
+  // Tick of an event counted from the first event.
+  pub fn tick_of(&self, event: &AerEvent) -> usize {
+    ((event.timestamp - self.start) as f64 / self.tick_us as f64) as usize
+  }
+
+  // Ticks replayed so far.
+  pub fn tick(&self) -> usize {
+    self.tick
+  }
+
+  pub fn is_done(&self) -> bool {
+    self.next >= self.events.len()
+  }
+
+  // Events of the next `ticks` ticks, ticks counted from the start of
+  // the window.
+  pub fn next_window(&mut self, ticks: usize) -> Vec<Event> {
+    let end = self.tick + ticks;
+    let mut window = Vec::new();
+    while self.next < self.events.len() {
+      let event = self.events[self.next];
+      let tick = self.tick_of(&event);
+      if tick >= end {
+        break;
+      }
+
+      if let Some(channel) = self.channel(&event) {
+        window.push(Event{
+          tick: tick - self.tick,
+          channel: channel,
+        });
+      }
+      self.next += 1;
+    }
+
+    self.tick = end;
+    window
+  }
+
+  // Back to the first event.
+  pub fn rewind(&mut self) {
+    self.next = 0;
+    self.tick = 0;
+  }
+}
@@ -0,0 +1,44 @@
+use Float;
+use std::default::Default;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub enum Optimizer {
+  SGD,
+  Adam,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct BPTTConfig {
+  pub optimizer: Optimizer,
+  pub learning_rate: Float,
+
+  // Adam moment decay rates and denominator offset
+  pub beta1: Float,
+  pub beta2: Float,
+  pub epsilon: Float,
+
+  // steepness of the fast sigmoid standing in for the threshold's
+  // derivative, per mV
+  pub surrogate_slope: Float,
+
+  // initial weights are uniform in +-weight_scale / sqrt(fan in)
+  pub weight_scale: Float,
+  pub seed: usize,
+}
+
+impl Default for BPTTConfig {
+  fn default() -> BPTTConfig {
+    BPTTConfig{
+      optimizer: Optimizer::Adam,
+      learning_rate: 0.01,
+      beta1: 0.9,
+      beta2: 0.999,
+      epsilon: 1e-8,
+      surrogate_slope: 1.0,
+      weight_scale: 10.0,
+      seed: 1,
+    }
+  }
+}
@@ -0,0 +1,93 @@
+use Float;
+use bptt::config::{BPTTConfig, Optimizer};
+
+// Weights of one fully connected layer, optionally with recurrent
+// connections among its own neurons, plus their gradients and optimizer
+// state. Weights are row-major, `weights[j * inputs + i]` from input i to
+// neuron j.
+pub struct Layer {
+  pub inputs: usize,
+  pub size: usize,
+
+  pub weights: Vec<Float>,
+  pub recurrent: Option<Vec<Float>>,
+
+  grad: Vec<Float>,
+  recurrent_grad: Vec<Float>,
+
+  // Adam first and second moments
+  m: Vec<Float>,
+  v: Vec<Float>,
+  recurrent_m: Vec<Float>,
+  recurrent_v: Vec<Float>,
+}
+
+impl Layer {
+  pub fn new(weights: Vec<Float>, recurrent: Option<Vec<Float>>, inputs: usize, size: usize) -> Layer {
+    let n = inputs * size;
+    let r = if recurrent.is_some() { size * size } else { 0 };
+    Layer{
+      inputs: inputs,
+      size: size,
+      weights: weights,
+      recurrent: recurrent,
+      grad: vec![0.0; n],
+      recurrent_grad: vec![0.0; r],
+      m: vec![0.0; n],
+      v: vec![0.0; n],
+      recurrent_m: vec![0.0; r],
+      recurrent_v: vec![0.0; r],
+    }
+  }
+
+  // Accumulates weight gradients from the loss gradients `dm` of this
+  // layer's membranes and the presynaptic spikes `pre` they received,
+  // through the feed-forward or the recurrent weights.
+  pub fn accumulate(&mut self, dm: &[Float], pre: &[Float], recurrent: bool) {
+    let (grad, n) = if recurrent {
+      (&mut self.recurrent_grad, self.size)
+    } else {
+      (&mut self.grad, self.inputs)
+    };
+
+    for (j, &d) in dm.iter().enumerate() {
+      if d == 0.0 {
+        continue;
+      }
+      for (i, &p) in pre.iter().enumerate() {
+        grad[j * n + i] += d * p;
+      }
+    }
+  }
+
+  // Applies and clears the accumulated gradients, averaged over `count`
+  // samples. `step` counts updates from one for Adam's bias correction.
+  pub fn update(&mut self, config: &BPTTConfig, count: usize, step: usize) {
+    let scale = 1.0 / count as Float;
+    apply(config, step, scale, &mut self.weights, &mut self.grad, &mut self.m, &mut self.v);
+    if let Some(ref mut recurrent) = self.recurrent {
+      apply(config, step, scale, recurrent, &mut self.recurrent_grad, &mut self.recurrent_m, &mut self.recurrent_v);
+    }
+  }
+}
+
+fn apply(config: &BPTTConfig, step: usize, scale: Float, w: &mut [Float], grad: &mut [Float], m: &mut [Float], v: &mut [Float]) {
+  let bias1 = 1.0 - config.beta1.powi(step as i32);
+  let bias2 = 1.0 - config.beta2.powi(step as i32);
+
+  for k in 0..w.len() {
+    let g = grad[k] * scale;
+    grad[k] = 0.0;
+
+    match config.optimizer {
+      Optimizer::SGD => {
+        w[k] -= config.learning_rate * g;
+      },
+      Optimizer::Adam => {
+        m[k] = config.beta1 * m[k] + (1.0 - config.beta1) * g;
+        v[k] = config.beta2 * v[k] + (1.0 - config.beta2) * g * g;
+        w[k] -= config.learning_rate * (m[k] / bias1) / ((v[k] / bias2).sqrt() + config.epsilon);
+      },
+    }
+  }
+}
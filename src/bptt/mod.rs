@@ -0,0 +1,7 @@
+pub use self::config::{BPTTConfig, Optimizer};
+pub use self::layer::Layer;
+pub use self::trainer::Trainer;
+
+pub mod config;
+pub mod layer;
+pub mod trainer;
@@ -0,0 +1,275 @@
+extern crate rand;
+
+use self::rand::{Rng, SeedableRng, StdRng};
+use std::f32;
+
+use Float;
+use network::{Network, NeuralError};
+use synapse::Synapse;
+use lif::{LIFNeuron, LIFConfig};
+use decoding::decoder::argmax;
+use bptt::config::BPTTConfig;
+use bptt::layer::Layer;
+
+// Trains layered networks of `LIFNeuron`s offline with backpropagation
+// through time, then builds them into a `Network`.
+//
+// Every connection has a delay of one tick, as in a `Network` with unit
+// synaptic delays, and the membrane follows `LIFNeuron` exactly: relative
+// to rest, u[t] = beta * u[t - 1] + gain * i[t] with a hard reset. The
+// refractory period and threshold adaptation of the neuron config are
+// turned off, for both training and `build`, as the model has neither. The
+// threshold's derivative is replaced by a fast sigmoid and gradients do
+// not flow through the reset.
+pub struct Trainer {
+  config: BPTTConfig,
+
+  neuron: LIFConfig,
+  tau: Float,
+
+  beta: Float,
+  gain: Float,
+  threshold: Float,
+  u_reset: Float,
+
+  inputs: usize,
+  layers: Vec<Layer>,
+
+  rng: StdRng,
+  step: usize,
+}
+
+// Membranes before reset and spikes of every layer at every tick.
+struct Record {
+  m: Vec<Vec<Vec<Float>>>,
+  s: Vec<Vec<Vec<Float>>>,
+}
+
+impl Trainer {
+  pub fn new(config: BPTTConfig, neuron: LIFConfig, tau: Float, inputs: usize) -> Trainer {
+    let neuron = LIFConfig{
+      refractory: 0.0,
+      adaptive: false,
+      ..neuron
+    };
+
+    // One tick of `LIFNeuron` is `1 / tau` Euler steps of the leak.
+    let steps = (1.0 / tau) as i32;
+    let beta = (1.0 - tau / neuron.tau_m).powi(steps);
+
+    let seed: &[_] = &[config.seed];
+    Trainer{
+      config: config,
+      neuron: neuron,
+      tau: tau,
+      beta: beta,
+      gain: neuron.tau_m * (1.0 - beta),
+      threshold: neuron.v_thresh - neuron.v_rest,
+      u_reset: neuron.v_reset - neuron.v_rest,
+      inputs: inputs,
+      layers: Vec::new(),
+      rng: SeedableRng::from_seed(seed),
+      step: 0,
+    }
+  }
+
+  pub fn add_layer(&mut self, size: usize, recurrent: bool) {
+    let inputs = self.layers.last().map_or(self.inputs, |l| l.size);
+    let weights = self.init_weights(inputs, size);
+    let recurrent = if recurrent {
+      Some(self.init_weights(size, size))
+    } else {
+      None
+    };
+    self.layers.push(Layer::new(weights, recurrent, inputs, size));
+  }
+
+  pub fn layers(&self) -> &[Layer] {
+    &self.layers
+  }
+
+  fn init_weights(&mut self, inputs: usize, size: usize) -> Vec<Float> {
+    let bound = self.config.weight_scale / (inputs as Float).sqrt();
+    (0..inputs * size).map(|_| (self.rng.gen::<Float>() * 2.0 - 1.0) * bound).collect()
+  }
+
+  // External current that makes an input neuron fire within the tick.
+  pub fn input_current(&self) -> Float {
+    2.0 * (self.threshold - self.beta * self.u_reset.min(0.0)) / self.gain
+  }
+
+  fn forward(&self, inputs: &[Vec<Float>]) -> Record {
+    let ticks = inputs.len();
+    let mut record = Record{
+      m: self.layers.iter().map(|l| vec![vec![0.0; l.size]; ticks]).collect(),
+      s: self.layers.iter().map(|l| vec![vec![0.0; l.size]; ticks]).collect(),
+    };
+
+    let mut u: Vec<Vec<Float>> = self.layers.iter().map(|l| vec![0.0; l.size]).collect();
+    for t in 1..ticks {
+      for (k, layer) in self.layers.iter().enumerate() {
+        let mut m = vec![0.0; layer.size];
+        let mut s = vec![0.0; layer.size];
+        {
+          let pre = if k == 0 { &inputs[t - 1] } else { &record.s[k - 1][t - 1] };
+          for j in 0..layer.size {
+            let mut i = 0.0;
+            for (p, &x) in pre.iter().enumerate() {
+              i += layer.weights[j * layer.inputs + p] * x;
+            }
+            if let Some(ref recurrent) = layer.recurrent {
+              for (p, &x) in record.s[k][t - 1].iter().enumerate() {
+                i += recurrent[j * layer.size + p] * x;
+              }
+            }
+
+            m[j] = self.beta * u[k][j] + self.gain * i;
+            if m[j] >= self.threshold {
+              s[j] = 1.0;
+              u[k][j] = self.u_reset;
+            } else {
+              u[k][j] = m[j];
+            }
+          }
+        }
+        record.m[k][t] = m;
+        record.s[k][t] = s;
+      }
+    }
+    record
+  }
+
+  // Output spike counts for a sequence of input spike vectors.
+  pub fn spike_counts(&self, inputs: &[Vec<Float>]) -> Vec<Float> {
+    self.counts(&self.forward(inputs))
+  }
+
+  fn counts(&self, record: &Record) -> Vec<Float> {
+    let last = record.s.len() - 1;
+    let size = self.layers[last].size;
+    (0..size).map(|j| record.s[last].iter().fold(0.0, |c, s| c + s[j])).collect()
+  }
+
+  pub fn predict(&self, inputs: &[Vec<Float>]) -> usize {
+    let counts = self.spike_counts(inputs);
+    argmax(&counts).unwrap_or(0)
+  }
+
+  // One optimizer step on a batch of (input sequence, label) samples,
+  // minimizing the cross-entropy of the softmax over output spike counts.
+  // Returns the mean loss.
+  pub fn train(&mut self, batch: &[(Vec<Vec<Float>>, usize)]) -> Float {
+    let mut loss = 0.0;
+    for &(ref inputs, label) in batch.iter() {
+      loss += self.backward(inputs, label);
+    }
+
+    self.step += 1;
+    for layer in self.layers.iter_mut() {
+      layer.update(&self.config, batch.len(), self.step);
+    }
+    loss / batch.len() as Float
+  }
+
+  fn surrogate(&self, m: Float) -> Float {
+    let x = self.config.surrogate_slope * (m - self.threshold).abs() + 1.0;
+    1.0 / (x * x)
+  }
+
+  fn backward(&mut self, inputs: &[Vec<Float>], label: usize) -> Float {
+    let record = self.forward(inputs);
+    let ticks = inputs.len();
+    let last = self.layers.len() - 1;
+
+    // softmax cross-entropy over the output counts
+    let size = self.layers[last].size;
+    let counts = self.counts(&record);
+    let max = counts.iter().cloned().fold(f32::NEG_INFINITY, Float::max);
+    let exps: Vec<Float> = counts.iter().map(|c| (c - max).exp()).collect();
+    let total = exps.iter().fold(0.0, |a, e| a + e);
+    let probs: Vec<Float> = exps.iter().map(|e| e / total).collect();
+    let loss = -probs[label].ln();
+    let d_counts: Vec<Float> = (0..size).map(|j| probs[j] - if j == label { 1.0 } else { 0.0 }).collect();
+
+    // dL/dm at t + 1, per layer
+    let mut dm_next: Vec<Vec<Float>> = self.layers.iter().map(|l| vec![0.0; l.size]).collect();
+
+    for t in (1..ticks).rev() {
+      let mut dm_now: Vec<Vec<Float>> = self.layers.iter().map(|l| vec![0.0; l.size]).collect();
+
+      for k in (0..self.layers.len()).rev() {
+        let layer = &self.layers[k];
+
+        // gradient reaching this layer's spikes at t
+        let mut ds = if k == last { d_counts.clone() } else { vec![0.0; layer.size] };
+        if k < last && t + 1 < ticks {
+          let next = &self.layers[k + 1];
+          for (j, &d) in dm_next[k + 1].iter().enumerate() {
+            for p in 0..layer.size {
+              ds[p] += self.gain * d * next.weights[j * next.inputs + p];
+            }
+          }
+        }
+        if let Some(ref recurrent) = layer.recurrent {
+          if t + 1 < ticks {
+            for (j, &d) in dm_next[k].iter().enumerate() {
+              for p in 0..layer.size {
+                ds[p] += self.gain * d * recurrent[j * layer.size + p];
+              }
+            }
+          }
+        }
+
+        for j in 0..layer.size {
+          let s = record.s[k][t][j];
+          let du = if t + 1 < ticks { self.beta * dm_next[k][j] } else { 0.0 };
+          dm_now[k][j] = ds[j] * self.surrogate(record.m[k][t][j]) + du * (1.0 - s);
+        }
+      }
+
+      for k in 0..self.layers.len() {
+        let dm: Vec<Float> = dm_now[k].iter().map(|d| d * self.gain).collect();
+        let pre = if k == 0 { inputs[t - 1].clone() } else { record.s[k - 1][t - 1].clone() };
+        self.layers[k].accumulate(&dm, &pre, false);
+        if self.layers[k].recurrent.is_some() {
+          let pre = record.s[k][t - 1].clone();
+          self.layers[k].accumulate(&dm, &pre, true);
+        }
+      }
+
+      dm_next = dm_now;
+    }
+
+    loss
+  }
+
+  // Adds the trained network to `network`: a layer of input neurons, to
+  // be driven with `input_current`, followed by every trained layer.
+  // `factory` makes a synapse of the given weight, which must have a
+  // delay of one tick and no plasticity. Returns the neuron ids of each
+  // layer, inputs first.
+  pub fn build<S: Synapse, F: Fn(Float) -> S>(&self, network: &mut Network<LIFNeuron, S>, factory: F) -> Result<Vec<Vec<usize>>, NeuralError> {
+    let neuron = LIFNeuron::new(self.tau, self.neuron);
+
+    let mut ids = Vec::new();
+    ids.push((0..self.inputs).map(|_| network.add_neuron(neuron)).collect::<Vec<usize>>());
+    for layer in self.layers.iter() {
+      ids.push((0..layer.size).map(|_| network.add_neuron(neuron)).collect::<Vec<usize>>());
+    }
+
+    for (k, layer) in self.layers.iter().enumerate() {
+      for j in 0..layer.size {
+        for i in 0..layer.inputs {
+          try!(network.add_synapse(factory(layer.weights[j * layer.inputs + i]), ids[k][i], ids[k + 1][j]));
+        }
+        if let Some(ref recurrent) = layer.recurrent {
+          for i in 0..layer.size {
+            try!(network.add_synapse(factory(recurrent[j * layer.size + i]), ids[k + 1][i], ids[k + 1][j]));
+          }
+        }
+      }
+    }
+
+    Ok(ids)
+  }
+}
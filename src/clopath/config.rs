@@ -0,0 +1,66 @@
+use Float;
+use std::default::Default;
+
+// Defaults follow the visual cortex fit of Clopath et al. (2010),
+// "Connectivity reflects coding: a model of voltage-based STDP with
+// homeostasis".
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ClopathConfig {
+  pub weight: Float,
+  pub min: Float,
+  pub max: Float,
+
+  // depression amplitude (per mV)
+  pub a_ltd: Float,
+
+  // potentiation amplitude (per mV^2 per ms)
+  pub a_ltp: Float,
+
+  // voltage thresholds for depression and potentiation (mV)
+  pub theta_neg: Float,
+  pub theta_pos: Float,
+
+  // presynaptic trace time constant
+  pub tau_x: Float,
+
+  // time constants of the low-pass filtered postsynaptic voltage
+  pub tau_neg: Float,
+  pub tau_pos: Float,
+
+  pub delay: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float,
+
+  // Homeostasis scales depression by ū²/u_ref², with ū the depolarisation
+  // above `u_rest` averaged over `tau_homeo` (mV, mV, ms). ū starts at
+  // `u_ref`, so depression starts at `a_ltd`.
+  pub u_ref: Float,
+  pub u_rest: Float,
+  pub tau_homeo: Float
+}
+
+impl Default for ClopathConfig {
+  fn default() -> ClopathConfig {
+    ClopathConfig{
+      weight: 1.0,
+      min: 0.0,
+      max: 3.0,
+      a_ltd: 14e-5,
+      a_ltp: 8e-5,
+      theta_neg: -70.6,
+      theta_pos: -45.3,
+      tau_x: 15.0,
+      tau_neg: 10.0,
+      tau_pos: 7.0,
+      delay: 1.0,
+      dendritic: 0.0,
+      // u_ref² = 60 mV²
+      u_ref: 7.746,
+      u_rest: -70.6,
+      tau_homeo: 1000.0
+    }
+  }
+}
@@ -0,0 +1,5 @@
+pub use self::config::ClopathConfig;
+pub use self::synapse::ClopathSynapse;
+
+pub mod config;
+pub mod synapse;
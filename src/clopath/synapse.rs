@@ -0,0 +1,139 @@
+use Float;
+use synapse::Synapse;
+use trace::Trace;
+use clopath::config::ClopathConfig;
+use fastexp::FastExp;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClopathSynapse<T: Trace> {
+  weight: Float,
+
+  min: Float,
+  max: Float,
+
+  a_ltd: Float,
+  a_ltp: Float,
+
+  theta_neg: Float,
+  theta_pos: Float,
+
+  // presynaptic spike trace
+  pre_trace: T,
+
+  // low-pass filtered postsynaptic voltage
+  u_neg: Float,
+  u_pos: Float,
+
+  tau_neg: Float,
+  tau_pos: Float,
+
+  // mean depolarisation above rest, scaling depression
+  u_mean: Float,
+  u_ref: Float,
+  u_rest: Float,
+  tau_homeo: Float,
+
+  delay: Float,
+  dendritic: Float,
+}
+
+impl<T: Trace> ClopathSynapse<T> {
+  pub fn new(config: ClopathConfig) -> ClopathSynapse<T> {
+    return ClopathSynapse{
+      pre_trace: T::new(config.tau_x, true),
+      weight: config.weight,
+      a_ltd: config.a_ltd,
+      a_ltp: config.a_ltp,
+      theta_neg: config.theta_neg,
+      theta_pos: config.theta_pos,
+      // filters start at the depression threshold so nothing happens
+      // until the neuron has been observed
+      u_neg: config.theta_neg,
+      u_pos: config.theta_neg,
+      tau_neg: config.tau_neg,
+      tau_pos: config.tau_pos,
+      u_mean: config.u_ref,
+      u_ref: config.u_ref,
+      u_rest: config.u_rest,
+      tau_homeo: config.tau_homeo,
+      delay: config.delay,
+      dendritic: config.dendritic,
+      min: config.min,
+      max: config.max,
+    }
+  }
+
+  fn integrate(&mut self, delta: Float) {
+    self.weight = self.weight + delta;
+    if self.weight > self.max {
+      self.weight = self.max;
+    } else if self.weight < self.min {
+      self.weight = self.min;
+    }
+  }
+}
+
+fn rectify(x: Float) -> Float {
+  if x > 0.0 { x } else { 0.0 }
+}
+
+impl<T: Trace> Synapse for ClopathSynapse<T> {
+  fn weight(&self) -> Float {
+    self.weight
+  }
+
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
+    self.delay
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
+  fn is_voltage_dependent(&self) -> bool {
+    true
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float { // delta
+    // Depression at presynaptic spike arrival when the slowly filtered
+    // voltage sits above `theta_neg`, stronger the more depolarised the
+    // neuron has been on average.
+    let a_ltd = self.a_ltd * (self.u_mean * self.u_mean) / (self.u_ref * self.u_ref);
+    let delta = -1.0 * a_ltd * rectify(self.u_neg - self.theta_neg);
+    self.integrate(delta);
+
+    self.pre_trace.update(1.0, now);
+    delta
+  }
+
+  fn post_recv(&mut self, _now: Float) -> Float { // delta
+    // Postsynaptic spikes only matter through the voltage.
+    0.0
+  }
+
+  // Called once per tick with the postsynaptic membrane potential.
+  fn post_voltage(&mut self, v: Float, now: Float) -> Float { // delta
+    // Potentiation needs a recent presynaptic spike, an instantaneous
+    // voltage above `theta_pos` and a filtered voltage above `theta_neg`.
+    let delta = self.a_ltp * self.pre_trace.read(now) *
+      rectify(v - self.theta_pos) * rectify(self.u_pos - self.theta_neg);
+    self.integrate(delta);
+
+    self.u_neg += (v - self.u_neg) * (1.0 - (-1.0 / self.tau_neg).fastexp());
+    self.u_pos += (v - self.u_pos) * (1.0 - (-1.0 / self.tau_pos).fastexp());
+    self.u_mean += (v - self.u_rest - self.u_mean) * (1.0 - (-1.0 / self.tau_homeo).fastexp());
+    delta
+  }
+}
@@ -0,0 +1,25 @@
+use Float;
+use std::default::Default;
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ConductanceConfig {
+  // decay time constants of the excitatory and inhibitory conductances
+  pub tau_exc: Float,
+  pub tau_inh: Float,
+
+  // reversal potentials
+  pub e_exc: Float,
+  pub e_inh: Float,
+}
+
+impl Default for ConductanceConfig {
+  fn default() -> ConductanceConfig {
+    ConductanceConfig{
+      tau_exc: 5.0,
+      tau_inh: 10.0,
+      e_exc: 0.0,
+      e_inh: -80.0,
+    }
+  }
+}
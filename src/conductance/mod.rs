@@ -0,0 +1,5 @@
+pub use self::config::ConductanceConfig;
+pub use self::neuron::ConductanceNeuron;
+
+pub mod config;
+pub mod neuron;
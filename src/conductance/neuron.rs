@@ -0,0 +1,103 @@
+use Float;
+
+use neuron::Neuron;
+use conductance::config::ConductanceConfig;
+
+// Wraps a neuron with conductance-based synapses. A spike delivered
+// through `recv_at` opens an excitatory conductance of its weight, or an
+// inhibitory conductance of its magnitude if the weight is negative. Both
+// decay exponentially per neuron and drive the wrapped neuron with
+// g(E_rev - v), so inhibition near its reversal potential shunts rather
+// than hyperpolarizes. Direct input through `recv` is still a current.
+#[derive(Debug, Clone, Copy)]
+pub struct ConductanceNeuron<N: Neuron> {
+  neuron: N,
+
+  g_exc: Float,
+  g_inh: Float,
+
+  // conductance arriving at the start of the next tick
+  g_exc_next: Float,
+  g_inh_next: Float,
+
+  tau_exc: Float,
+  tau_inh: Float,
+
+  e_exc: Float,
+  e_inh: Float,
+}
+
+impl<N: Neuron> ConductanceNeuron<N> {
+  pub fn new(config: ConductanceConfig, neuron: N) -> ConductanceNeuron<N> {
+    ConductanceNeuron{
+      neuron: neuron,
+      g_exc: 0.0,
+      g_inh: 0.0,
+      g_exc_next: 0.0,
+      g_inh_next: 0.0,
+      tau_exc: config.tau_exc,
+      tau_inh: config.tau_inh,
+      e_exc: config.e_exc,
+      e_inh: config.e_inh,
+    }
+  }
+
+  pub fn neuron(&self) -> &N {
+    &self.neuron
+  }
+
+  pub fn g_exc(&self) -> Float {
+    self.g_exc
+  }
+
+  pub fn g_inh(&self) -> Float {
+    self.g_inh
+  }
+}
+
+impl<N: Neuron> Neuron for ConductanceNeuron<N> {
+  fn recv(&mut self, v: Float) -> Float {
+    self.neuron.recv(v)
+  }
+
+  fn recv_at(&mut self, v: Float, offset: Float) -> Float {
+    // Split the spike between this tick and the next, as the point
+    // neurons do with their input charge.
+    if v < 0.0 {
+      self.g_inh -= v * (1.0 - offset);
+      self.g_inh_next -= v * offset;
+      self.g_inh
+    } else {
+      self.g_exc += v * (1.0 - offset);
+      self.g_exc_next += v * offset;
+      self.g_exc
+    }
+  }
+
+  fn recv_conductance(&mut self, g: Float, e_rev: Float) -> Float {
+    self.neuron.recv_conductance(g, e_rev)
+  }
+
+  fn tick(&mut self, tau: Float) {
+    self.neuron.recv_conductance(self.g_exc, self.e_exc);
+    self.neuron.recv_conductance(self.g_inh, self.e_inh);
+    self.neuron.tick(tau);
+
+    self.g_exc = self.g_exc * (-tau / self.tau_exc).exp() + self.g_exc_next;
+    self.g_inh = self.g_inh * (-tau / self.tau_inh).exp() + self.g_inh_next;
+    self.g_exc_next = 0.0;
+    self.g_inh_next = 0.0;
+  }
+
+  fn threshold(&mut self) -> Float {
+    self.neuron.threshold()
+  }
+
+  fn reset(&mut self) {
+    self.neuron.reset()
+  }
+
+  fn voltage(&self) -> Float {
+    self.neuron.voltage()
+  }
+}
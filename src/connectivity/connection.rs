@@ -0,0 +1,11 @@
+use Float;
+
+// A synapse as seen from outside the network: presynaptic and
+// postsynaptic neuron ids, weight and delay in ticks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Connection {
+  pub pre: usize,
+  pub post: usize,
+  pub weight: Float,
+  pub delay: Float,
+}
@@ -0,0 +1,71 @@
+use Float;
+
+// A weight matrix in compressed sparse row form, rows indexing
+// presynaptic and columns postsynaptic neurons. The layout matches
+// `scipy.sparse.csr_matrix((data, indices, indptr), shape=(rows, cols))`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix {
+  pub rows: usize,
+  pub cols: usize,
+
+  // entries of row `r` are `indptr[r]..indptr[r + 1]`
+  pub indptr: Vec<usize>,
+  pub indices: Vec<usize>,
+  pub data: Vec<Float>,
+}
+
+impl SparseMatrix {
+  // The nonzero entries of a row-major dense matrix.
+  pub fn from_dense(rows: usize, cols: usize, dense: &[Float]) -> SparseMatrix {
+    assert_eq!(dense.len(), rows * cols);
+
+    let mut matrix = SparseMatrix{
+      rows: rows,
+      cols: cols,
+      indptr: vec![0],
+      indices: Vec::new(),
+      data: Vec::new(),
+    };
+    for r in 0..rows {
+      for c in 0..cols {
+        let w = dense[r * cols + c];
+        if w != 0.0 {
+          matrix.indices.push(c);
+          matrix.data.push(w);
+        }
+      }
+      matrix.indptr.push(matrix.indices.len());
+    }
+    matrix
+  }
+
+  // Whether the row pointers, column indices and data agree with each
+  // other and the shape. The other methods expect a valid matrix.
+  pub fn is_valid(&self) -> bool {
+    self.indptr.len() == self.rows + 1 &&
+      self.indptr[0] == 0 &&
+      self.indptr.windows(2).all(|w| w[0] <= w[1]) &&
+      self.indptr[self.rows] == self.indices.len() &&
+      self.data.len() == self.indices.len() &&
+      self.indices.iter().all(|&c| c < self.cols)
+  }
+
+  pub fn to_dense(&self) -> Vec<Float> {
+    let mut dense = vec![0.0; self.rows * self.cols];
+    for (r, c, w) in self.entries() {
+      dense[r * self.cols + c] += w;
+    }
+    dense
+  }
+
+  // Every stored (row, column, value).
+  pub fn entries(&self) -> Vec<(usize, usize, Float)> {
+    let mut entries = Vec::with_capacity(self.data.len());
+    for r in 0..self.rows {
+      for i in self.indptr[r]..self.indptr[r + 1] {
+        entries.push((r, self.indices[i], self.data[i]));
+      }
+    }
+    entries
+  }
+}
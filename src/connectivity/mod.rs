@@ -0,0 +1,5 @@
+pub use self::connection::Connection;
+pub use self::matrix::SparseMatrix;
+
+pub mod connection;
+pub mod matrix;
@@ -0,0 +1,36 @@
+use Float;
+use std::default::Default;
+
+// Conversion of trained ReLU networks to spiking networks of IF neurons
+// with data-based weight normalization, following Diehl et al. (2015),
+// "Fast-classifying, high-accuracy spiking deep networks through weight
+// and threshold balancing" and Rueckauer et al. (2017), "Conversion of
+// continuous-valued deep networks to efficient event-driven networks for
+// image classification".
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub enum Normalization {
+  // scale each layer by its largest activation
+  Max,
+
+  // scale each layer by the `percentile` of its positive activations,
+  // robust to outliers
+  Percentile,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ConversionConfig {
+  pub normalization: Normalization,
+  pub percentile: Float,
+}
+
+impl Default for ConversionConfig {
+  fn default() -> ConversionConfig {
+    ConversionConfig{
+      normalization: Normalization::Percentile,
+      percentile: 99.9,
+    }
+  }
+}
@@ -0,0 +1,268 @@
+use std::io;
+
+use Float;
+use network::{Network, NeuralError};
+use synapse::Synapse;
+use decoding::decoder::argmax;
+use export::npy::Array;
+use conversion::config::{ConversionConfig, Normalization};
+use conversion::neuron::IFNeuron;
+use conversion::layer::Layer;
+
+// Converts a feed-forward ReLU network, trained elsewhere and loaded from
+// .npy arrays, into populations of `IFNeuron`s. Hidden layers are ReLU
+// and the last is linear; the spiking network reads out the last layer's
+// spike counts. Inputs are expected in [0, 1] and drive a population of
+// input neurons as constant currents.
+pub struct Converter {
+  config: ConversionConfig,
+  input_shape: Vec<usize>,
+  layers: Vec<Layer>,
+}
+
+impl Converter {
+  // `input_shape` is [inputs], or [channels, height, width] ahead of a
+  // convolution.
+  pub fn new(config: ConversionConfig, input_shape: &[usize]) -> Converter {
+    Converter{
+      config: config,
+      input_shape: input_shape.to_vec(),
+      layers: Vec::new(),
+    }
+  }
+
+  pub fn layers(&self) -> &[Layer] {
+    &self.layers
+  }
+
+  pub fn inputs(&self) -> usize {
+    self.input_shape.iter().fold(1, |n, &d| n * d)
+  }
+
+  // Shape of the activations the next layer receives.
+  fn shape(&self) -> Vec<usize> {
+    match self.layers.last() {
+      Some(&Layer::Dense{outputs, ..}) => vec![outputs],
+      Some(&Layer::Conv2d{output, ..}) => output.to_vec(),
+      None => self.input_shape.clone(),
+    }
+  }
+
+  // Appends a dense layer with weights of shape (outputs, inputs) and an
+  // optional bias of shape (outputs,). Any preceding shape is flattened.
+  pub fn add_dense(&mut self, weights: &Array, bias: Option<&Array>) -> io::Result<()> {
+    let inputs = self.shape().iter().fold(1, |n, &d| n * d);
+    if weights.shape.len() != 2 || weights.shape[1] != inputs {
+      return Err(shape_error("dense weights must be (outputs, inputs)"));
+    }
+    let outputs = weights.shape[0];
+    let bias = try!(bias_values(bias, outputs));
+
+    self.layers.push(Layer::Dense{
+      inputs: inputs,
+      outputs: outputs,
+      weights: weights.data.iter().map(|&w| w as Float).collect(),
+      bias: bias,
+    });
+    Ok(())
+  }
+
+  // Appends a 2D convolution with weights of shape (out_channels,
+  // in_channels, kernel_height, kernel_width) and an optional bias of
+  // shape (out_channels,).
+  pub fn add_conv2d(&mut self, weights: &Array, bias: Option<&Array>, stride: usize, padding: usize) -> io::Result<()> {
+    let shape = self.shape();
+    if shape.len() != 3 {
+      return Err(shape_error("convolutions need a (channels, height, width) input"));
+    }
+    if weights.shape.len() != 4 || weights.shape[1] != shape[0] {
+      return Err(shape_error("convolution weights must be (out_channels, in_channels, height, width)"));
+    }
+
+    let kernel = [weights.shape[2], weights.shape[3]];
+    if stride == 0 || shape[1] + 2 * padding < kernel[0] || shape[2] + 2 * padding < kernel[1] {
+      return Err(shape_error("convolution kernel larger than its input"));
+    }
+    let output = [
+      weights.shape[0],
+      (shape[1] + 2 * padding - kernel[0]) / stride + 1,
+      (shape[2] + 2 * padding - kernel[1]) / stride + 1,
+    ];
+    let bias = try!(bias_values(bias, output[0]));
+
+    self.layers.push(Layer::Conv2d{
+      input: [shape[0], shape[1], shape[2]],
+      output: output,
+      kernel: kernel,
+      stride: stride,
+      padding: padding,
+      weights: weights.data.iter().map(|&w| w as Float).collect(),
+      bias: bias,
+    });
+    Ok(())
+  }
+
+  // Activations of every layer for one input.
+  pub fn forward(&self, input: &[Float]) -> Vec<Vec<Float>> {
+    let connections: Vec<Vec<(usize, usize, Float)>> = self.layers.iter().map(|l| l.connections()).collect();
+    self.activations(&connections, input)
+  }
+
+  // Class of the largest output of the rate-based network.
+  pub fn predict(&self, input: &[Float]) -> usize {
+    match self.forward(input).last() {
+      Some(output) => argmax(output).unwrap_or(0),
+      None => 0,
+    }
+  }
+
+  fn activations(&self, connections: &[Vec<(usize, usize, Float)>], input: &[Float]) -> Vec<Vec<Float>> {
+    let mut activations: Vec<Vec<Float>> = Vec::with_capacity(self.layers.len());
+    for (k, layer) in self.layers.iter().enumerate() {
+      let mut a = layer.biases();
+      {
+        let x = if k == 0 { input } else { &activations[k - 1][..] };
+        for &(i, o, w) in connections[k].iter() {
+          a[o] += w * x[i];
+        }
+      }
+
+      if k + 1 < self.layers.len() {
+        for v in a.iter_mut() {
+          if *v < 0.0 {
+            *v = 0.0;
+          }
+        }
+      }
+      activations.push(a);
+    }
+    activations
+  }
+
+  // Data-based normalization: scales every layer so the chosen statistic
+  // of its activations over `samples` maps to a firing rate of one spike
+  // per tick, multiplying weights by lambda[l - 1] / lambda[l] and biases
+  // by 1 / lambda[l]. The rate-based network's predictions are unchanged.
+  pub fn normalize(&mut self, samples: &[Vec<Float>]) {
+    let connections: Vec<Vec<(usize, usize, Float)>> = self.layers.iter().map(|l| l.connections()).collect();
+
+    let mut values: Vec<Vec<Float>> = vec![Vec::new(); self.layers.len()];
+    for sample in samples.iter() {
+      for (k, a) in self.activations(&connections, sample).iter().enumerate() {
+        values[k].extend(a.iter().filter(|&&v| v > 0.0).cloned());
+      }
+    }
+
+    let mut previous = 1.0;
+    for (layer, values) in self.layers.iter_mut().zip(values.iter_mut()) {
+      let lambda = match self.config.normalization {
+        Normalization::Max => values.iter().fold(0.0, |m, &v| if v > m { v } else { m }),
+        Normalization::Percentile => percentile(values, self.config.percentile),
+      };
+      if lambda <= 0.0 {
+        // a silent layer passes the scale on unchanged
+        layer.rescale(1.0, 1.0 / previous);
+        continue;
+      }
+
+      layer.rescale(previous / lambda, 1.0 / lambda);
+      previous = lambda;
+    }
+  }
+
+  // Adds the network to `network`: a population of input neurons then
+  // one per layer, with a synapse for every connection. `factory` makes
+  // a synapse of the given weight, which should have a delay of one tick
+  // and no plasticity. Returns the neuron ids of each population, inputs
+  // first.
+  pub fn build<S: Synapse, F: Fn(Float) -> S>(&self, network: &mut Network<IFNeuron, S>, factory: F) -> Result<Vec<Vec<usize>>, NeuralError> {
+    let mut ids = Vec::new();
+    ids.push((0..self.inputs()).map(|_| network.add_neuron(IFNeuron::default())).collect::<Vec<usize>>());
+    for layer in self.layers.iter() {
+      ids.push((0..layer.outputs()).map(|_| network.add_neuron(IFNeuron::default())).collect::<Vec<usize>>());
+    }
+
+    for (k, layer) in self.layers.iter().enumerate() {
+      for (i, o, w) in layer.connections() {
+        if w == 0.0 {
+          continue;
+        }
+        try!(network.add_synapse(factory(w), ids[k][i], ids[k + 1][o]));
+      }
+    }
+    Ok(ids)
+  }
+
+  // One tick of input currents for `network`: the sample on the input
+  // population and the biases on every layer.
+  pub fn input_currents(&self, ids: &[Vec<usize>], sample: &[Float], inputs: &mut [Float]) {
+    for (&id, &x) in ids[0].iter().zip(sample.iter()) {
+      inputs[id] += x;
+    }
+    for (layer, layer_ids) in self.layers.iter().zip(ids[1..].iter()) {
+      for (&id, b) in layer_ids.iter().zip(layer.biases()) {
+        inputs[id] += b;
+      }
+    }
+  }
+
+  // Presents `sample` for `ticks` ticks from rest and returns the spike
+  // counts of the last layer.
+  pub fn run<S: Synapse>(&self, network: &mut Network<IFNeuron, S>, ids: &[Vec<usize>], sample: &[Float], ticks: usize) -> Vec<Float> {
+    for layer_ids in ids.iter() {
+      for &id in layer_ids.iter() {
+        if let Some(neuron) = network.get_neuron_mut(id) {
+          neuron.clear();
+        }
+      }
+    }
+
+    let neuron_count = network.get_neuron_count();
+    let mut inputs = vec![0.0; neuron_count];
+    self.input_currents(ids, sample, &mut inputs);
+
+    let last = &ids[ids.len() - 1];
+    let mut counts = vec![0.0; last.len()];
+    let mut outputs = vec![0.0; neuron_count];
+    for _ in 0..ticks {
+      for o in outputs.iter_mut() {
+        *o = 0.0;
+      }
+      network.tick(1, &inputs, &mut outputs);
+      for (count, &id) in counts.iter_mut().zip(last.iter()) {
+        if outputs[id] > 0.0 {
+          *count += 1.0;
+        }
+      }
+    }
+    counts
+  }
+}
+
+fn bias_values(bias: Option<&Array>, outputs: usize) -> io::Result<Vec<Float>> {
+  match bias {
+    Some(bias) => {
+      if bias.shape != [outputs] {
+        return Err(shape_error("bias must be (outputs,)"));
+      }
+      Ok(bias.data.iter().map(|&b| b as Float).collect())
+    },
+    None => Ok(vec![0.0; outputs]),
+  }
+}
+
+fn shape_error(message: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+// The `p`th percentile of `values` by nearest rank, sorting them.
+fn percentile(values: &mut [Float], p: Float) -> Float {
+  if values.is_empty() {
+    return 0.0;
+  }
+  values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+  let rank = (p / 100.0 * values.len() as Float).ceil() as usize;
+  let index = if rank == 0 { 0 } else if rank > values.len() { values.len() - 1 } else { rank - 1 };
+  values[index]
+}
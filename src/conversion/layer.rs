@@ -0,0 +1,113 @@
+use Float;
+
+// A trained layer in PyTorch layout. Activations are flat, in
+// channel-major (C, H, W) order for convolutions.
+#[derive(Debug, Clone)]
+pub enum Layer {
+  // weights (outputs, inputs) as `nn.Linear.weight`
+  Dense {
+    inputs: usize,
+    outputs: usize,
+    weights: Vec<Float>,
+    bias: Vec<Float>,
+  },
+
+  // weights (out_channels, in_channels, kernel_height, kernel_width) as
+  // `nn.Conv2d.weight`, with zero padding
+  Conv2d {
+    input: [usize; 3],
+    output: [usize; 3],
+    kernel: [usize; 2],
+    stride: usize,
+    padding: usize,
+    weights: Vec<Float>,
+    bias: Vec<Float>,
+  },
+}
+
+impl Layer {
+  pub fn inputs(&self) -> usize {
+    match *self {
+      Layer::Dense{inputs, ..} => inputs,
+      Layer::Conv2d{input, ..} => input[0] * input[1] * input[2],
+    }
+  }
+
+  pub fn outputs(&self) -> usize {
+    match *self {
+      Layer::Dense{outputs, ..} => outputs,
+      Layer::Conv2d{output, ..} => output[0] * output[1] * output[2],
+    }
+  }
+
+  // Bias of every output.
+  pub fn biases(&self) -> Vec<Float> {
+    match *self {
+      Layer::Dense{ref bias, ..} => bias.clone(),
+      Layer::Conv2d{output, ref bias, ..} => {
+        // shared across each channel's positions
+        let positions = output[1] * output[2];
+        (0..output[0] * positions).map(|o| bias[o / positions]).collect()
+      },
+    }
+  }
+
+  // (input, output, weight) of every connection.
+  pub fn connections(&self) -> Vec<(usize, usize, Float)> {
+    let mut connections = Vec::new();
+    match *self {
+      Layer::Dense{inputs, outputs, ref weights, ..} => {
+        for o in 0..outputs {
+          for i in 0..inputs {
+            connections.push((i, o, weights[o * inputs + i]));
+          }
+        }
+      },
+      Layer::Conv2d{input, output, kernel, stride, padding, ref weights, ..} => {
+        let (in_channels, height, width) = (input[0], input[1], input[2]);
+        let (out_channels, out_height, out_width) = (output[0], output[1], output[2]);
+        let (kernel_height, kernel_width) = (kernel[0], kernel[1]);
+
+        for oc in 0..out_channels {
+          for oy in 0..out_height {
+            for ox in 0..out_width {
+              let o = (oc * out_height + oy) * out_width + ox;
+              for ic in 0..in_channels {
+                for ky in 0..kernel_height {
+                  for kx in 0..kernel_width {
+                    // position in the unpadded input
+                    let y = (oy * stride + ky) as isize - padding as isize;
+                    let x = (ox * stride + kx) as isize - padding as isize;
+                    if y < 0 || x < 0 || y >= height as isize || x >= width as isize {
+                      continue;
+                    }
+
+                    let i = (ic * height + y as usize) * width + x as usize;
+                    let w = weights[((oc * in_channels + ic) * kernel_height + ky) * kernel_width + kx];
+                    connections.push((i, o, w));
+                  }
+                }
+              }
+            }
+          }
+        }
+      },
+    }
+    connections
+  }
+
+  // Scale weights by `weight_factor` and biases by `bias_factor`.
+  pub fn rescale(&mut self, weight_factor: Float, bias_factor: Float) {
+    match *self {
+      Layer::Dense{ref mut weights, ref mut bias, ..} |
+      Layer::Conv2d{ref mut weights, ref mut bias, ..} => {
+        for w in weights.iter_mut() {
+          *w *= weight_factor;
+        }
+        for b in bias.iter_mut() {
+          *b *= bias_factor;
+        }
+      },
+    }
+  }
+}
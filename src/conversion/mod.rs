@@ -0,0 +1,9 @@
+pub use self::config::{ConversionConfig, Normalization};
+pub use self::neuron::IFNeuron;
+pub use self::layer::Layer;
+pub use self::converter::Converter;
+
+pub mod config;
+pub mod neuron;
+pub mod layer;
+pub mod converter;
@@ -0,0 +1,79 @@
+use Float;
+use std::default::Default;
+
+use neuron::Neuron;
+
+// Non-leaky integrate-and-fire neuron with reset by subtraction, so the
+// excess charge at a spike carries over and the firing rate tracks the
+// input current: a constant input `i` fires at `i / v_thresh` spikes per
+// tick, saturating at one.
+#[derive(Debug, Clone, Copy)]
+pub struct IFNeuron {
+  pub v: Float,
+
+  v_thresh: Float,
+
+  // Input for the next tick and the part carried over by spikes arriving
+  // part-way through the current one.
+  i: Float,
+  i_next: Float,
+}
+
+impl Default for IFNeuron {
+  fn default() -> IFNeuron {
+    IFNeuron::new(1.0)
+  }
+}
+
+impl IFNeuron {
+  pub fn new(v_thresh: Float) -> IFNeuron {
+    IFNeuron{
+      v: 0.0,
+      v_thresh: v_thresh,
+      i: 0.0,
+      i_next: 0.0,
+    }
+  }
+
+  // Clear the membrane and pending input, e.g. between samples.
+  pub fn clear(&mut self) {
+    self.v = 0.0;
+    self.i = 0.0;
+    self.i_next = 0.0;
+  }
+}
+
+impl Neuron for IFNeuron {
+  fn recv(&mut self, v: Float) -> Float {
+    self.i += v;
+    self.i
+  }
+
+  fn recv_at(&mut self, v: Float, offset: Float) -> Float {
+    self.i += v * (1.0 - offset);
+    self.i_next += v * offset;
+    self.i
+  }
+
+  fn threshold(&mut self) -> Float {
+    if self.v >= self.v_thresh {
+      1.0
+    } else {
+      0.0
+    }
+  }
+
+  fn voltage(&self) -> Float {
+    self.v
+  }
+
+  fn reset(&mut self) {
+    self.v -= self.v_thresh;
+  }
+
+  fn tick(&mut self, _tau: Float) {
+    self.v += self.i;
+    self.i = self.i_next;
+    self.i_next = 0.0;
+  }
+}
@@ -0,0 +1,91 @@
+use Float;
+use network::NeuralError;
+use encoding::event::Event;
+use decoding::decoder::{Decoder, Classifier, argmax};
+
+// Spike-count classification: every channel is assigned a class and the
+// class whose channels fire most on average wins, as in the readout of
+// Diehl & Cook (2015).
+pub struct SpikeCountClassifier {
+  assignments: Vec<usize>,
+
+  // channels assigned to each class
+  sizes: Vec<usize>,
+}
+
+impl SpikeCountClassifier {
+  pub fn new(assignments: &[usize]) -> SpikeCountClassifier {
+    let classes = assignments.iter().map(|&c| c + 1).max().unwrap_or(0);
+    let mut sizes = vec![0; classes];
+    for &c in assignments.iter() {
+      sizes[c] += 1;
+    }
+
+    SpikeCountClassifier{
+      assignments: assignments.to_vec(),
+      sizes: sizes,
+    }
+  }
+
+  // Assigns every channel the class it responded to most, on average,
+  // over labelled recordings. Silent channels go to class 0 and events on
+  // channels past `channels` are ignored, as in `decode`.
+  pub fn fit(channels: usize, classes: usize, recordings: &[(Vec<Event>, usize)]) -> Result<SpikeCountClassifier, NeuralError> {
+    let mut responses = vec![0.0; channels * classes];
+    let mut presentations = vec![0.0; classes];
+    for &(ref events, label) in recordings.iter() {
+      if label >= classes {
+        return Err(NeuralError::InvalidShape)
+      }
+
+      presentations[label] += 1.0;
+      for event in events.iter().filter(|event| event.channel < channels) {
+        responses[event.channel * classes + label] += 1.0;
+      }
+    }
+
+    let assignments: Vec<usize> = (0..channels).map(|i| {
+      let rates: Vec<Float> = (0..classes).map(|c| {
+        if presentations[c] > 0.0 { responses[i * classes + c] / presentations[c] } else { 0.0 }
+      }).collect();
+      argmax(&rates).unwrap_or(0)
+    }).collect();
+
+    let mut classifier = SpikeCountClassifier::new(&assignments);
+    classifier.sizes.resize(classes, 0);
+    Ok(classifier)
+  }
+
+  pub fn assignments(&self) -> &[usize] {
+    &self.assignments
+  }
+}
+
+impl Decoder for SpikeCountClassifier {
+  // Mean spike count per channel of each class.
+  fn decode(&self, events: &[Event], _ticks: usize) -> Vec<Float> {
+    let mut counts = vec![0.0; self.sizes.len()];
+    for event in events.iter() {
+      if let Some(&c) = self.assignments.get(event.channel) {
+        counts[c] += 1.0;
+      }
+    }
+
+    for (count, &size) in counts.iter_mut().zip(self.sizes.iter()) {
+      if size > 0 {
+        *count /= size as Float;
+      }
+    }
+    counts
+  }
+}
+
+impl Classifier for SpikeCountClassifier {
+  fn classify(&self, events: &[Event], ticks: usize) -> Option<usize> {
+    let counts = self.decode(events, ticks);
+    if counts.iter().all(|&c| c == 0.0) {
+      return None;
+    }
+    argmax(&counts)
+  }
+}
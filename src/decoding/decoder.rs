@@ -0,0 +1,25 @@
+use Float;
+use encoding::event::Event;
+
+pub trait Decoder {
+  // Values read from `events` recorded over `ticks` ticks.
+  fn decode(&self, events: &[Event], ticks: usize) -> Vec<Float>;
+}
+
+pub trait Classifier {
+  // Class predicted from `events` recorded over `ticks` ticks, `None`
+  // when the recording holds no evidence for any class.
+  fn classify(&self, events: &[Event], ticks: usize) -> Option<usize>;
+}
+
+// Index of the largest score, the first on ties.
+pub fn argmax(scores: &[Float]) -> Option<usize> {
+  let mut best = None;
+  for (i, &score) in scores.iter().enumerate() {
+    match best {
+      Some((_, s)) if s >= score => {},
+      _ => { best = Some((i, score)); },
+    }
+  }
+  best.map(|(i, _)| i)
+}
@@ -0,0 +1,60 @@
+use Float;
+use encoding::event::Event;
+use decoding::decoder::{Decoder, Classifier};
+
+// First-spike classification: the class of the channel that fires first
+// wins. Simultaneous first spikes are broken by how many channels of each
+// class fired on that tick, then by the lower class.
+pub struct FirstSpikeClassifier {
+  assignments: Vec<usize>,
+  classes: usize,
+}
+
+impl FirstSpikeClassifier {
+  pub fn new(assignments: &[usize]) -> FirstSpikeClassifier {
+    FirstSpikeClassifier{
+      assignments: assignments.to_vec(),
+      classes: assignments.iter().map(|&c| c + 1).max().unwrap_or(0),
+    }
+  }
+}
+
+impl Decoder for FirstSpikeClassifier {
+  // Tick of the first spike of each class, `ticks` for silent classes.
+  fn decode(&self, events: &[Event], ticks: usize) -> Vec<Float> {
+    let mut first = vec![ticks as Float; self.classes];
+    for event in events.iter() {
+      if let Some(&c) = self.assignments.get(event.channel) {
+        if (event.tick as Float) < first[c] {
+          first[c] = event.tick as Float;
+        }
+      }
+    }
+    first
+  }
+}
+
+impl Classifier for FirstSpikeClassifier {
+  fn classify(&self, events: &[Event], _ticks: usize) -> Option<usize> {
+    let tick = match events.iter()
+      .filter(|e| e.channel < self.assignments.len())
+      .map(|e| e.tick)
+      .min() {
+      Some(tick) => tick,
+      None => return None,
+    };
+
+    let mut votes = vec![0; self.classes];
+    for event in events.iter().filter(|e| e.tick == tick && e.channel < self.assignments.len()) {
+      votes[self.assignments[event.channel]] += 1;
+    }
+
+    let mut best = 0;
+    for (c, &v) in votes.iter().enumerate() {
+      if v > votes[best] {
+        best = c;
+      }
+    }
+    Some(best)
+  }
+}
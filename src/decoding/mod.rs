@@ -0,0 +1,13 @@
+pub use self::monitor::SpikeMonitor;
+pub use self::decoder::{Decoder, Classifier};
+pub use self::count::SpikeCountClassifier;
+pub use self::first::FirstSpikeClassifier;
+pub use self::vector::PopulationVectorDecoder;
+pub use self::rate::RateDecoder;
+
+pub mod monitor;
+pub mod decoder;
+pub mod count;
+pub mod first;
+pub mod vector;
+pub mod rate;
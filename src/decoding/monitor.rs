@@ -0,0 +1,64 @@
+use Float;
+use std::mem;
+use encoding::event::Event;
+
+// Records the spikes of a set of neurons as events, the channel of each
+// event being the neuron's position in `neuron_ids` and its tick counted
+// from the start of the recording.
+pub struct SpikeMonitor {
+  neuron_ids: Vec<usize>,
+  events: Vec<Event>,
+  ticks: usize,
+}
+
+impl SpikeMonitor {
+  pub fn new(neuron_ids: &[usize]) -> SpikeMonitor {
+    SpikeMonitor{
+      neuron_ids: neuron_ids.to_vec(),
+      events: Vec::new(),
+      ticks: 0,
+    }
+  }
+
+  pub fn neuron_ids(&self) -> &[usize] {
+    &self.neuron_ids
+  }
+
+  // Record the `outputs` of a single tick of the `Network`. Every
+  // positive output counts as one spike.
+  pub fn observe(&mut self, outputs: &[Float]) {
+    for (i, &neuron_id) in self.neuron_ids.iter().enumerate() {
+      if outputs[neuron_id] > 0.0 {
+        self.events.push(Event{
+          tick: self.ticks,
+          channel: i,
+        });
+      }
+    }
+    self.ticks += 1;
+  }
+
+  // Ticks recorded so far.
+  pub fn ticks(&self) -> usize {
+    self.ticks
+  }
+
+  pub fn events(&self) -> &[Event] {
+    &self.events
+  }
+
+  // The recording with every channel replaced by its neuron id, for
+  // export.
+  pub fn neuron_events(&self) -> Vec<Event> {
+    self.events.iter().map(|event| Event{
+      tick: event.tick,
+      channel: self.neuron_ids[event.channel],
+    }).collect()
+  }
+
+  // Take the recording and start a new one.
+  pub fn take_events(&mut self) -> Vec<Event> {
+    self.ticks = 0;
+    mem::replace(&mut self.events, Vec::new())
+  }
+}
@@ -0,0 +1,53 @@
+use Float;
+use encoding::event::Event;
+use decoding::decoder::Decoder;
+
+// Firing rates from spike trains filtered by a causal exponential kernel
+// normalized to unit area, in spikes per tick.
+pub struct RateDecoder {
+  channels: usize,
+  tau: Float,
+}
+
+impl RateDecoder {
+  pub fn new(tau: Float, channels: usize) -> RateDecoder {
+    RateDecoder{
+      channels: channels,
+      tau: tau,
+    }
+  }
+
+  // Rate of every channel at every tick, tick-major.
+  pub fn rates(&self, events: &[Event], ticks: usize) -> Vec<Float> {
+    let decay = (-1.0 / self.tau).exp();
+    let mut rates = vec![0.0; ticks * self.channels];
+    let mut rate = vec![0.0; self.channels];
+
+    let mut events: Vec<&Event> = events.iter().filter(|e| e.channel < self.channels).collect();
+    events.sort_by(|a, b| a.tick.cmp(&b.tick));
+
+    let mut next = 0;
+    for t in 0..ticks {
+      for r in rate.iter_mut() {
+        *r *= decay;
+      }
+      while next < events.len() && events[next].tick == t {
+        rate[events[next].channel] += 1.0 / self.tau;
+        next += 1;
+      }
+      rates[t * self.channels..(t + 1) * self.channels].clone_from_slice(&rate);
+    }
+    rates
+  }
+}
+
+impl Decoder for RateDecoder {
+  // Rate of every channel at the last tick.
+  fn decode(&self, events: &[Event], ticks: usize) -> Vec<Float> {
+    if ticks == 0 {
+      return vec![0.0; self.channels];
+    }
+    let rates = self.rates(events, ticks);
+    rates[(ticks - 1) * self.channels..].to_vec()
+  }
+}
@@ -0,0 +1,49 @@
+use Float;
+use std::f32::consts::PI;
+use encoding::event::Event;
+use decoding::decoder::Decoder;
+
+// Population-vector decoding of a circular variable (Georgopoulos et al.
+// 1986): each channel votes for its preferred angle, in radians, with its
+// spike count.
+pub struct PopulationVectorDecoder {
+  preferred: Vec<Float>,
+}
+
+impl PopulationVectorDecoder {
+  pub fn new(preferred: &[Float]) -> PopulationVectorDecoder {
+    PopulationVectorDecoder{
+      preferred: preferred.to_vec(),
+    }
+  }
+
+  // `channels` preferred angles evenly spaced around the circle from 0.
+  pub fn uniform(channels: usize) -> PopulationVectorDecoder {
+    let preferred: Vec<Float> = (0..channels)
+      .map(|i| 2.0 * PI * i as Float / channels as Float)
+      .collect();
+    PopulationVectorDecoder::new(&preferred)
+  }
+}
+
+impl Decoder for PopulationVectorDecoder {
+  // The decoded angle in [0, 2pi) and the length of the population vector
+  // in spikes per tick. The angle is 0 when the vector vanishes.
+  fn decode(&self, events: &[Event], ticks: usize) -> Vec<Float> {
+    let (mut x, mut y) = (0.0, 0.0);
+    for event in events.iter() {
+      if let Some(&angle) = self.preferred.get(event.channel) {
+        x += angle.cos();
+        y += angle.sin();
+      }
+    }
+
+    let mut angle = if x == 0.0 && y == 0.0 { 0.0 } else { y.atan2(x) };
+    if angle < 0.0 {
+      angle += 2.0 * PI;
+    }
+
+    let length = (x * x + y * y).sqrt();
+    vec![angle, if ticks > 0 { length / ticks as Float } else { 0.0 }]
+  }
+}
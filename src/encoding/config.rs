@@ -0,0 +1,113 @@
+use Float;
+use std::default::Default;
+
+// Encoders turn values into spikes on a number of channels over a window
+// of ticks. Unless noted values are expected in [0, 1].
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct PoissonConfig {
+  // spike probability per tick at a value of 1
+  pub max_rate: Float,
+
+  pub seed: usize,
+}
+
+impl Default for PoissonConfig {
+  fn default() -> PoissonConfig {
+    PoissonConfig{
+      max_rate: 0.1,
+      seed: 1,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct LatencyConfig {
+  // values at or below this never spike
+  pub threshold: Float,
+
+  // spike at (1 - x) of the window when linear, otherwise at the time an
+  // RC circuit charged by x crosses `threshold`, tau * ln(x / (x - threshold))
+  pub linear: bool,
+  pub tau: Float,
+}
+
+impl Default for LatencyConfig {
+  fn default() -> LatencyConfig {
+    LatencyConfig{
+      threshold: 0.01,
+      linear: true,
+      tau: 5.0,
+    }
+  }
+}
+
+// Thorpe & Gautrais (1998), "Rank order coding".
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct RankOrderConfig {
+  // values at or below this never spike
+  pub threshold: Float,
+
+  // ticks between consecutive ranks
+  pub spacing: usize,
+}
+
+impl Default for RankOrderConfig {
+  fn default() -> RankOrderConfig {
+    RankOrderConfig{
+      threshold: 0.0,
+      spacing: 1,
+    }
+  }
+}
+
+// Gaussian receptive fields of Bohte et al. (2002), "Error-backpropagation
+// in temporally encoded networks of spiking neurons". Each value is spread
+// over `fields` channels whose responses are latency coded.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct PopulationConfig {
+  pub fields: usize,
+
+  // range of the encoded values
+  pub min: Float,
+  pub max: Float,
+
+  // width of the fields, sigma = (max - min) / (beta * (fields - 2))
+  pub beta: Float,
+
+  // responses at or below this never spike
+  pub threshold: Float,
+}
+
+impl Default for PopulationConfig {
+  fn default() -> PopulationConfig {
+    PopulationConfig{
+      fields: 8,
+      min: 0.0,
+      max: 1.0,
+      beta: 1.5,
+      threshold: 0.1,
+    }
+  }
+}
+
+// Send-on-delta coding of analog signals, as in event cameras and
+// cochleae. Values are not limited to [0, 1].
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct DeltaConfig {
+  // change in the signal that emits a spike
+  pub threshold: Float,
+}
+
+impl Default for DeltaConfig {
+  fn default() -> DeltaConfig {
+    DeltaConfig{
+      threshold: 0.1,
+    }
+  }
+}
@@ -0,0 +1,94 @@
+use Float;
+use std::cmp;
+use network::NeuralError;
+use encoding::config::DeltaConfig;
+use encoding::event::Event;
+use encoding::encoder::Encoder;
+
+// Delta (threshold-crossing) coding of analog signals. Each input has an
+// ON channel `2 * i` that spikes every time the signal rises `threshold`
+// above its reference and an OFF channel `2 * i + 1` for falls, the
+// reference following in steps of `threshold`. The reference starts at
+// the first sample and carries over between calls.
+pub struct DeltaEncoder {
+  inputs: usize,
+  threshold: Float,
+  references: Option<Vec<Float>>,
+}
+
+impl DeltaEncoder {
+  pub fn new(config: DeltaConfig, inputs: usize) -> Result<DeltaEncoder, NeuralError> {
+    // a step of zero would never catch up with the signal
+    if !(config.threshold > 0.0) {
+      return Err(NeuralError::InvalidConfig)
+    }
+
+    Ok(DeltaEncoder{
+      inputs: inputs,
+      threshold: config.threshold,
+      references: None,
+    })
+  }
+
+  // Forget the references, the next sample starts a new signal.
+  pub fn reset(&mut self) {
+    self.references = None;
+  }
+}
+
+impl Encoder for DeltaEncoder {
+  fn channels(&self) -> usize {
+    2 * self.inputs
+  }
+
+  // `values` holds one sample of every input per tick, laid out like the
+  // inputs of `Network::tick`. Only the ticks `values` has every sample of
+  // are encoded.
+  fn encode(&mut self, values: &[Float], ticks: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+    if self.inputs == 0 {
+      return events;
+    }
+    let ticks = cmp::min(ticks, values.len() / self.inputs);
+    if ticks == 0 {
+      return events;
+    }
+
+    let mut start = 0;
+    if self.references.is_none() {
+      self.references = Some(values[..self.inputs].to_vec());
+      start = 1;
+    }
+
+    let references = self.references.as_mut().unwrap();
+    for t in start..ticks {
+      let samples = &values[t * self.inputs..(t + 1) * self.inputs];
+      for (i, (&x, reference)) in samples.iter().zip(references.iter_mut()).enumerate() {
+        let n = crossings(x - *reference, self.threshold);
+        if n == 0 {
+          continue;
+        }
+
+        *reference = (*reference as f64 + n as f64 * self.threshold as f64) as Float;
+        let channel = if n > 0 { 2 * i } else { 2 * i + 1 };
+        for _ in 0..n.abs() {
+          events.push(Event{
+            tick: t,
+            channel: channel,
+          });
+        }
+      }
+    }
+    events
+  }
+}
+
+// Whole thresholds in `delta`, towards zero. Stepping the reference one
+// threshold at a time drifts and stalls once the threshold is below its
+// precision. A change of exactly n thresholds counts as n despite the
+// rounding of `threshold`.
+fn crossings(delta: Float, threshold: Float) -> i64 {
+  let n = delta as f64 / threshold as f64;
+  let whole = (n.abs() * (1.0 + 1e-6)).floor() as i64;
+  if n < 0.0 { -whole } else { whole }
+}
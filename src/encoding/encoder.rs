@@ -0,0 +1,11 @@
+use Float;
+use encoding::event::Event;
+
+pub trait Encoder {
+  // Number of spike channels produced.
+  fn channels(&self) -> usize;
+
+  // Spikes for `values` presented over a window of `ticks` ticks, sorted
+  // by tick.
+  fn encode(&mut self, values: &[Float], ticks: usize) -> Vec<Event>;
+}
@@ -0,0 +1,28 @@
+use Float;
+
+// A spike on an encoder channel `tick` ticks into the encoded window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Event {
+  pub tick: usize,
+  pub channel: usize,
+}
+
+// Adds `amplitude` for every event to an input buffer laid out like the
+// inputs of `Network::tick`, tick-major over `neuron_count` neurons, with
+// channel `c` driving neuron `offset + c`. Events past the end of the
+// buffer are dropped.
+pub fn fill(events: &[Event], amplitude: Float, offset: usize, neuron_count: usize, buffer: &mut [Float]) {
+  for event in events.iter() {
+    let index = event.tick * neuron_count + offset + event.channel;
+    if offset + event.channel < neuron_count && index < buffer.len() {
+      buffer[index] += amplitude;
+    }
+  }
+}
+
+// A buffer of `ticks` ticks over `channels` neurons holding `events`.
+pub fn dense(events: &[Event], amplitude: Float, channels: usize, ticks: usize) -> Vec<Float> {
+  let mut buffer = vec![0.0; ticks * channels];
+  fill(events, amplitude, 0, channels, &mut buffer);
+  buffer
+}
@@ -0,0 +1,65 @@
+use Float;
+use encoding::config::LatencyConfig;
+use encoding::event::Event;
+use encoding::encoder::Encoder;
+
+// Time-to-first-spike coding: each channel spikes at most once, larger
+// values earlier.
+pub struct LatencyEncoder {
+  inputs: usize,
+  threshold: Float,
+  linear: bool,
+  tau: Float,
+}
+
+impl LatencyEncoder {
+  pub fn new(config: LatencyConfig, inputs: usize) -> LatencyEncoder {
+    LatencyEncoder{
+      inputs: inputs,
+      threshold: config.threshold,
+      linear: config.linear,
+      tau: config.tau,
+    }
+  }
+
+  // Tick at which a value spikes within a window of `ticks` ticks, if at
+  // all.
+  pub fn latency(&self, x: Float, ticks: usize) -> Option<usize> {
+    if x <= self.threshold || ticks == 0 {
+      return None;
+    }
+
+    let x = if x > 1.0 { 1.0 } else { x };
+    let t = if self.linear {
+      ((1.0 - x) * (ticks - 1) as Float).round()
+    } else {
+      (self.tau * (x / (x - self.threshold)).ln()).round()
+    };
+
+    if t < ticks as Float {
+      Some(t as usize)
+    } else {
+      None
+    }
+  }
+}
+
+impl Encoder for LatencyEncoder {
+  fn channels(&self) -> usize {
+    self.inputs
+  }
+
+  fn encode(&mut self, values: &[Float], ticks: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+    for (i, &x) in values.iter().take(self.inputs).enumerate() {
+      if let Some(t) = self.latency(x, ticks) {
+        events.push(Event{
+          tick: t,
+          channel: i,
+        });
+      }
+    }
+    events.sort();
+    events
+  }
+}
@@ -0,0 +1,17 @@
+pub use self::config::{PoissonConfig, LatencyConfig, RankOrderConfig, PopulationConfig, DeltaConfig};
+pub use self::event::{Event, fill, dense};
+pub use self::encoder::Encoder;
+pub use self::poisson::PoissonEncoder;
+pub use self::latency::LatencyEncoder;
+pub use self::rank::RankOrderEncoder;
+pub use self::population::PopulationEncoder;
+pub use self::delta::DeltaEncoder;
+
+pub mod config;
+pub mod event;
+pub mod encoder;
+pub mod poisson;
+pub mod latency;
+pub mod rank;
+pub mod population;
+pub mod delta;
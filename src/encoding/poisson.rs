@@ -0,0 +1,48 @@
+extern crate rand;
+
+use self::rand::{Rng, SeedableRng, StdRng};
+
+use Float;
+use encoding::config::PoissonConfig;
+use encoding::event::Event;
+use encoding::encoder::Encoder;
+
+// Rate coding: every tick each channel spikes independently with a
+// probability proportional to its value.
+pub struct PoissonEncoder {
+  inputs: usize,
+  max_rate: Float,
+  rng: StdRng,
+}
+
+impl PoissonEncoder {
+  pub fn new(config: PoissonConfig, inputs: usize) -> PoissonEncoder {
+    let seed: &[_] = &[config.seed];
+    PoissonEncoder{
+      inputs: inputs,
+      max_rate: config.max_rate,
+      rng: SeedableRng::from_seed(seed),
+    }
+  }
+}
+
+impl Encoder for PoissonEncoder {
+  fn channels(&self) -> usize {
+    self.inputs
+  }
+
+  fn encode(&mut self, values: &[Float], ticks: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+    for t in 0..ticks {
+      for (i, &x) in values.iter().take(self.inputs).enumerate() {
+        if self.rng.gen::<Float>() < x * self.max_rate {
+          events.push(Event{
+            tick: t,
+            channel: i,
+          });
+        }
+      }
+    }
+    events
+  }
+}
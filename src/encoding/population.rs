@@ -0,0 +1,77 @@
+use Float;
+use network::NeuralError;
+use encoding::config::PopulationConfig;
+use encoding::event::Event;
+use encoding::encoder::Encoder;
+
+// Population coding: each value drives `fields` channels with overlapping
+// Gaussian receptive fields, and each channel spikes once at a latency
+// decreasing with its response. Channel `i * fields + j` is field `j` of
+// input `i`.
+pub struct PopulationEncoder {
+  inputs: usize,
+  fields: usize,
+  centers: Vec<Float>,
+  sigma: Float,
+  threshold: Float,
+}
+
+impl PopulationEncoder {
+  pub fn new(config: PopulationConfig, inputs: usize) -> Result<PopulationEncoder, NeuralError> {
+    // the outer two fields need an inner one between them
+    if config.fields <= 2 {
+      return Err(NeuralError::InvalidConfig)
+    }
+
+    // the outer two fields are centred beyond the range
+    let inner = (config.fields - 2) as Float;
+    let range = config.max - config.min;
+    let centers = (0..config.fields)
+      .map(|j| config.min + (2.0 * j as Float - 1.0) / 2.0 * range / inner)
+      .collect();
+
+    Ok(PopulationEncoder{
+      inputs: inputs,
+      fields: config.fields,
+      centers: centers,
+      sigma: range / (config.beta * inner),
+      threshold: config.threshold,
+    })
+  }
+
+  // Responses in (0, 1] of every field to a value.
+  pub fn responses(&self, x: Float) -> Vec<Float> {
+    self.centers.iter().map(|&mu| {
+      let d = (x - mu) / self.sigma;
+      (-0.5 * d * d).exp()
+    }).collect()
+  }
+}
+
+impl Encoder for PopulationEncoder {
+  fn channels(&self) -> usize {
+    self.inputs * self.fields
+  }
+
+  fn encode(&mut self, values: &[Float], ticks: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+    if ticks == 0 {
+      return events;
+    }
+
+    for (i, &x) in values.iter().take(self.inputs).enumerate() {
+      for (j, &r) in self.responses(x).iter().enumerate() {
+        if r <= self.threshold {
+          continue;
+        }
+
+        events.push(Event{
+          tick: ((1.0 - r) * (ticks - 1) as Float).round() as usize,
+          channel: i * self.fields + j,
+        });
+      }
+    }
+    events.sort();
+    events
+  }
+}
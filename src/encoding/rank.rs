@@ -0,0 +1,46 @@
+use Float;
+use std::cmp;
+use encoding::config::RankOrderConfig;
+use encoding::event::Event;
+use encoding::encoder::Encoder;
+
+// Rank-order coding: channels spike once each in order of decreasing
+// value, `spacing` ticks apart, so only the order carries information.
+// Ties go to the lower channel.
+pub struct RankOrderEncoder {
+  inputs: usize,
+  threshold: Float,
+  spacing: usize,
+}
+
+impl RankOrderEncoder {
+  pub fn new(config: RankOrderConfig, inputs: usize) -> RankOrderEncoder {
+    RankOrderEncoder{
+      inputs: inputs,
+      threshold: config.threshold,
+      spacing: config.spacing,
+    }
+  }
+}
+
+impl Encoder for RankOrderEncoder {
+  fn channels(&self) -> usize {
+    self.inputs
+  }
+
+  fn encode(&mut self, values: &[Float], ticks: usize) -> Vec<Event> {
+    let mut ranked: Vec<usize> = (0..cmp::min(values.len(), self.inputs))
+      .filter(|&i| values[i] > self.threshold)
+      .collect();
+    // stable, so equal values keep channel order
+    ranked.sort_by(|&a, &b| values[b].partial_cmp(&values[a]).unwrap());
+
+    ranked.iter().enumerate()
+      .map(|(rank, &i)| Event{
+        tick: rank * self.spacing,
+        channel: i,
+      })
+      .take_while(|event| event.tick < ticks)
+      .collect()
+  }
+}
@@ -0,0 +1,47 @@
+use std::io;
+use std::io::{Read, Write};
+
+use encoding::event::Event;
+use export::bytes::{put_u32, put_u64, get, slice, invalid};
+
+// Compact binary spike format, all integers little-endian:
+//
+//   magic    4 bytes  "NSPK"
+//   version  u32      1
+//   count    u64      number of spikes
+//   spikes   count x (tick u32, channel u32)
+const MAGIC: &'static [u8] = b"NSPK";
+const VERSION: u32 = 1;
+const HEADER_BYTES: usize = 16;
+
+pub fn write_spikes_binary<W: Write>(mut writer: W, events: &[Event]) -> io::Result<()> {
+  let mut buffer = Vec::with_capacity(HEADER_BYTES + 8 * events.len());
+  buffer.extend(MAGIC.iter());
+  put_u32(&mut buffer, VERSION);
+  put_u64(&mut buffer, events.len() as u64);
+  for event in events.iter() {
+    put_u32(&mut buffer, event.tick as u32);
+    put_u32(&mut buffer, event.channel as u32);
+  }
+  writer.write_all(&buffer)
+}
+
+pub fn read_spikes_binary<R: Read>(mut reader: R) -> io::Result<Vec<Event>> {
+  let mut data = Vec::new();
+  try!(reader.read_to_end(&mut data));
+
+  if try!(slice(&data, 0, 4)) != MAGIC {
+    return Err(invalid("not a spike file"));
+  }
+  if get(try!(slice(&data, 4, 4))) != VERSION as u64 {
+    return Err(invalid("unsupported spike file version"));
+  }
+
+  let count = get(try!(slice(&data, 8, 8))) as usize;
+  let len = try!(count.checked_mul(8).ok_or(invalid("invalid spike count")));
+  let body = try!(slice(&data, HEADER_BYTES, len));
+  Ok(body.chunks(8).map(|chunk| Event{
+    tick: get(&chunk[..4]) as usize,
+    channel: get(&chunk[4..]) as usize,
+  }).collect())
+}
@@ -0,0 +1,35 @@
+use std::io;
+
+// Little-endian encoding shared by the binary formats.
+
+pub fn put_u16(buffer: &mut Vec<u8>, v: u16) {
+  buffer.push(v as u8);
+  buffer.push((v >> 8) as u8);
+}
+
+pub fn put_u32(buffer: &mut Vec<u8>, v: u32) {
+  put_u16(buffer, v as u16);
+  put_u16(buffer, (v >> 16) as u16);
+}
+
+pub fn put_u64(buffer: &mut Vec<u8>, v: u64) {
+  put_u32(buffer, v as u32);
+  put_u32(buffer, (v >> 32) as u32);
+}
+
+// Little-endian integer of up to 8 bytes.
+pub fn get(bytes: &[u8]) -> u64 {
+  bytes.iter().rev().fold(0, |v, &b| (v << 8) | b as u64)
+}
+
+// `len` bytes at `offset`, or an error if the data ends first.
+pub fn slice(data: &[u8], offset: usize, len: usize) -> io::Result<&[u8]> {
+  if offset.checked_add(len).map_or(true, |end| end > data.len()) {
+    return Err(invalid("unexpected end of data"));
+  }
+  Ok(&data[offset..offset + len])
+}
+
+pub fn invalid(message: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
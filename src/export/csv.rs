@@ -0,0 +1,107 @@
+use std::io;
+use std::io::{Read, Write, BufRead, BufReader};
+
+use Float;
+use encoding::event::Event;
+use connectivity::Connection;
+use export::bytes::invalid;
+
+// Spikes are written as a header line `t,i` then one `tick,channel` row
+// per spike, the layout of examples/results/spikes.csv. States are
+// written as a header `k,x0,x1,...` then one row per recorded state with
+// its index and values. Connections are written as a header
+// `pre,post,weight,delay` then one row per synapse.
+
+pub fn write_spikes_csv<W: Write>(mut writer: W, events: &[Event]) -> io::Result<()> {
+  try!(writeln!(writer, "t,i"));
+  for event in events.iter() {
+    try!(writeln!(writer, "{},{}", event.tick, event.channel));
+  }
+  Ok(())
+}
+
+pub fn read_spikes_csv<R: Read>(reader: R) -> io::Result<Vec<Event>> {
+  let rows = try!(read_rows(reader, "t"));
+  rows.iter().map(|row| {
+    if row.len() != 2 {
+      return Err(invalid("expected two columns"));
+    }
+    Ok(Event{
+      tick: try!(row[0].parse().map_err(|_| invalid("invalid tick"))),
+      channel: try!(row[1].parse().map_err(|_| invalid("invalid channel"))),
+    })
+  }).collect()
+}
+
+pub fn write_states_csv<W: Write>(mut writer: W, states: &[Vec<Float>]) -> io::Result<()> {
+  let width = states.first().map_or(0, |s| s.len());
+  try!(write!(writer, "k"));
+  for i in 0..width {
+    try!(write!(writer, ",x{}", i));
+  }
+  try!(writeln!(writer, ""));
+
+  for (k, state) in states.iter().enumerate() {
+    try!(write!(writer, "{}", k));
+    for x in state.iter() {
+      try!(write!(writer, ",{}", x));
+    }
+    try!(writeln!(writer, ""));
+  }
+  Ok(())
+}
+
+pub fn read_states_csv<R: Read>(reader: R) -> io::Result<Vec<Vec<Float>>> {
+  let rows = try!(read_rows(reader, "k"));
+  rows.iter().map(|row| {
+    row.iter().skip(1).map(|x| x.parse().map_err(|_| invalid("invalid value"))).collect()
+  }).collect()
+}
+
+pub fn write_connections_csv<W: Write>(mut writer: W, connections: &[Connection]) -> io::Result<()> {
+  try!(writeln!(writer, "pre,post,weight,delay"));
+  for c in connections.iter() {
+    try!(writeln!(writer, "{},{},{},{}", c.pre, c.post, c.weight, c.delay));
+  }
+  Ok(())
+}
+
+pub fn read_connections_csv<R: Read>(reader: R) -> io::Result<Vec<Connection>> {
+  let rows = try!(read_rows(reader, "pre"));
+  rows.iter().map(|row| {
+    if row.len() != 4 {
+      return Err(invalid("expected four columns"));
+    }
+    Ok(Connection{
+      pre: try!(row[0].parse().map_err(|_| invalid("invalid neuron id"))),
+      post: try!(row[1].parse().map_err(|_| invalid("invalid neuron id"))),
+      weight: try!(row[2].parse().map_err(|_| invalid("invalid weight"))),
+      delay: try!(row[3].parse().map_err(|_| invalid("invalid delay"))),
+    })
+  }).collect()
+}
+
+// Fields of every non-empty line after a header starting with `first`.
+fn read_rows<R: Read>(reader: R, first: &str) -> io::Result<Vec<Vec<String>>> {
+  let mut lines = BufReader::new(reader).lines();
+  match lines.next() {
+    Some(header) => {
+      let header = try!(header);
+      if header.trim().split(',').next() != Some(first) {
+        return Err(invalid("unexpected header"));
+      }
+    },
+    None => return Err(invalid("missing header")),
+  }
+
+  let mut rows = Vec::new();
+  for line in lines {
+    let line = try!(line);
+    let line = line.trim();
+    if line.is_empty() {
+      continue;
+    }
+    rows.push(line.split(',').map(|field| field.trim().to_string()).collect());
+  }
+  Ok(rows)
+}
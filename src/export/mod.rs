@@ -0,0 +1,12 @@
+pub use self::csv::{write_spikes_csv, read_spikes_csv, write_states_csv, read_states_csv,
+  write_connections_csv, read_connections_csv};
+pub use self::binary::{write_spikes_binary, read_spikes_binary};
+pub use self::npy::{Array, write_npy, write_npy_spikes, write_npy_states, read_npy, open_npy};
+pub use self::npz::{Npz, read_npz};
+
+pub mod csv;
+pub mod binary;
+pub mod npy;
+pub mod npz;
+
+mod bytes;
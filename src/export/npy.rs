@@ -0,0 +1,255 @@
+use std::io;
+use std::io::{Read, Write};
+use std::fs::File;
+use std::path::Path;
+
+use Float;
+use encoding::event::Event;
+use export::bytes::{put_u16, put_u32, get, slice, invalid};
+
+// NumPy .npy arrays. Spikes are written as a (n, 2) array of '<u4' with
+// columns tick and channel, states as a (rows, width) array of '<f4'.
+// Any numeric array can be read back.
+
+const MAGIC: &'static [u8] = b"\x93NUMPY";
+
+// A numeric array in row-major order. Values are widened to f64, which
+// holds every 32-bit integer and float exactly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Array {
+  pub shape: Vec<usize>,
+  pub data: Vec<f64>,
+}
+
+impl Array {
+  pub fn to_floats(&self) -> Vec<Float> {
+    self.data.iter().map(|&x| x as Float).collect()
+  }
+
+  pub fn to_events(&self) -> io::Result<Vec<Event>> {
+    if self.shape.len() != 2 || self.shape[1] != 2 {
+      return Err(invalid("spikes must be an (n, 2) array"));
+    }
+    Ok(self.data.chunks(2).map(|row| Event{
+      tick: row[0] as usize,
+      channel: row[1] as usize,
+    }).collect())
+  }
+
+  pub fn to_states(&self) -> io::Result<Vec<Vec<Float>>> {
+    if self.shape.len() != 2 {
+      return Err(invalid("states must be a 2-d array"));
+    }
+    if self.shape[1] == 0 {
+      return Ok(vec![Vec::new(); self.shape[0]]);
+    }
+    Ok(self.data.chunks(self.shape[1]).map(|row| {
+      row.iter().map(|&x| x as Float).collect()
+    }).collect())
+  }
+}
+
+// Writes `data` as a '<f4' array of the given shape.
+pub fn write_npy<W: Write>(mut writer: W, shape: &[usize], data: &[Float]) -> io::Result<()> {
+  assert_eq!(shape.iter().fold(1, |n, &d| n * d), data.len());
+  let mut body = Vec::with_capacity(4 * data.len());
+  for &x in data.iter() {
+    put_u32(&mut body, x.to_bits());
+  }
+  writer.write_all(&npy_bytes("<f4", shape, &body))
+}
+
+pub fn write_npy_spikes<W: Write>(mut writer: W, events: &[Event]) -> io::Result<()> {
+  writer.write_all(&spikes_bytes(events))
+}
+
+pub fn write_npy_states<W: Write>(mut writer: W, states: &[Vec<Float>]) -> io::Result<()> {
+  writer.write_all(&try!(states_bytes(states)))
+}
+
+pub fn spikes_bytes(events: &[Event]) -> Vec<u8> {
+  let mut body = Vec::with_capacity(8 * events.len());
+  for event in events.iter() {
+    put_u32(&mut body, event.tick as u32);
+    put_u32(&mut body, event.channel as u32);
+  }
+  npy_bytes("<u4", &[events.len(), 2], &body)
+}
+
+pub fn states_bytes(states: &[Vec<Float>]) -> io::Result<Vec<u8>> {
+  let width = states.first().map_or(0, |s| s.len());
+  let mut data = Vec::with_capacity(states.len() * width);
+  for state in states.iter() {
+    if state.len() != width {
+      return Err(invalid("states differ in length"));
+    }
+    data.extend(state.iter().cloned());
+  }
+
+  let mut bytes = Vec::new();
+  try!(write_npy(&mut bytes, &[states.len(), width], &data));
+  Ok(bytes)
+}
+
+// A version 1.0 file: magic, version, header length, then a Python dict
+// literal padded with spaces so the data starts on a 64 byte boundary.
+fn npy_bytes(descr: &str, shape: &[usize], body: &[u8]) -> Vec<u8> {
+  let dims: Vec<String> = shape.iter().map(|d| d.to_string()).collect();
+  let shape = if dims.len() == 1 {
+    format!("({},)", dims[0])
+  } else {
+    format!("({})", dims.join(", "))
+  };
+
+  let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+  while (MAGIC.len() + 4 + header.len() + 1) % 64 != 0 {
+    header.push(' ');
+  }
+  header.push('\n');
+
+  let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + body.len());
+  bytes.extend(MAGIC.iter());
+  bytes.push(1);
+  bytes.push(0);
+  put_u16(&mut bytes, header.len() as u16);
+  bytes.extend(header.as_bytes().iter());
+  bytes.extend(body.iter());
+  bytes
+}
+
+pub fn read_npy<R: Read>(mut reader: R) -> io::Result<Array> {
+  let mut data = Vec::new();
+  try!(reader.read_to_end(&mut data));
+  parse_npy(&data)
+}
+
+pub fn open_npy<P: AsRef<Path>>(path: P) -> io::Result<Array> {
+  read_npy(try!(File::open(path)))
+}
+
+pub fn parse_npy(data: &[u8]) -> io::Result<Array> {
+  if try!(slice(data, 0, 6)) != MAGIC {
+    return Err(invalid("not an npy file"));
+  }
+
+  // versions 2.0 and 3.0 widen the header length to 4 bytes
+  let (header_len, start) = match try!(slice(data, 6, 1))[0] {
+    1 => (get(try!(slice(data, 8, 2))) as usize, 10),
+    2 | 3 => (get(try!(slice(data, 8, 4))) as usize, 12),
+    _ => return Err(invalid("unsupported npy version")),
+  };
+  let header = try!(String::from_utf8(try!(slice(data, start, header_len)).to_vec())
+    .map_err(|_| invalid("invalid npy header")));
+
+  let descr = try!(header_value(&header, "descr"));
+  let descr = descr.trim_matches(|c| c == '\'' || c == '"');
+  let fortran_order = try!(header_value(&header, "fortran_order")) == "True";
+  let shape: Vec<usize> = try!(try!(header_value(&header, "shape"))
+    .trim_matches(|c| c == '(' || c == ')')
+    .split(',')
+    .map(|d| d.trim())
+    .filter(|d| !d.is_empty())
+    .map(|d| d.parse().map_err(|_| invalid("invalid npy shape")))
+    .collect::<io::Result<Vec<usize>>>());
+
+  let (big_endian, kind, size) = try!(parse_descr(descr));
+  let count = try!(shape.iter().fold(Some(1), |n: Option<usize>, &d| n.and_then(|n| n.checked_mul(d)))
+    .ok_or(invalid("invalid npy shape")));
+  let len = try!(count.checked_mul(size).ok_or(invalid("invalid npy shape")));
+  let body = try!(slice(data, start + header_len, len));
+
+  let values: Vec<f64> = body.chunks(size).map(|chunk| {
+    let bits = if big_endian {
+      chunk.iter().fold(0, |v, &b| (v << 8) | b as u64)
+    } else {
+      get(chunk)
+    };
+    match (kind, size) {
+      ('f', 4) => f32::from_bits(bits as u32) as f64,
+      ('f', _) => f64::from_bits(bits),
+      ('i', _) => {
+        // sign extend
+        let shift = 64 - 8 * size;
+        ((bits << shift) as i64 >> shift) as f64
+      },
+      _ => bits as f64,
+    }
+  }).collect();
+
+  let data = if fortran_order && shape.len() > 1 {
+    transpose(&shape, &values)
+  } else {
+    values
+  };
+
+  Ok(Array{
+    shape: shape,
+    data: data,
+  })
+}
+
+// The text of `key`'s value in a header dict.
+fn header_value<'a>(header: &'a str, key: &str) -> io::Result<&'a str> {
+  let pattern = format!("'{}':", key);
+  let start = match header.find(&pattern) {
+    Some(i) => i + pattern.len(),
+    None => return Err(invalid("incomplete npy header")),
+  };
+
+  let rest = header[start..].trim();
+  let end = if rest.starts_with('(') {
+    rest.find(')').map(|i| i + 1)
+  } else {
+    rest.find(',').or(rest.find('}'))
+  };
+  match end {
+    Some(end) => Ok(rest[..end].trim()),
+    None => Err(invalid("incomplete npy header")),
+  }
+}
+
+// Byte order, kind and size of a numeric dtype such as '<f4' or '|u1'.
+fn parse_descr(descr: &str) -> io::Result<(bool, char, usize)> {
+  let mut chars = descr.chars();
+  let order = chars.next();
+  let kind = chars.next();
+  let size = chars.as_str().parse::<usize>().ok();
+
+  match (order, kind, size) {
+    (Some(order), Some(kind), Some(size)) => {
+      let valid = match kind {
+        'f' => size == 4 || size == 8,
+        'i' | 'u' => size == 1 || size == 2 || size == 4 || size == 8,
+        'b' => size == 1,
+        _ => false,
+      };
+      if !valid || !(order == '<' || order == '>' || order == '|' || order == '=') {
+        return Err(invalid("unsupported npy dtype"));
+      }
+      Ok((order == '>', kind, size))
+    },
+    _ => Err(invalid("unsupported npy dtype")),
+  }
+}
+
+// Column-major values of an array to row-major.
+fn transpose(shape: &[usize], values: &[f64]) -> Vec<f64> {
+  (0..values.len()).map(|i| {
+    // row-major index to column-major offset
+    let mut rest = i;
+    let mut offset = 0;
+    let mut stride = values.len();
+    for (axis, &d) in shape.iter().enumerate() {
+      stride /= d;
+      let index = rest / stride;
+      rest %= stride;
+
+      let mut fortran_stride = 1;
+      for &e in shape[..axis].iter() {
+        fortran_stride *= e;
+      }
+      offset += index * fortran_stride;
+    }
+    values[offset]
+  }).collect()
+}
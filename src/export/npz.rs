@@ -0,0 +1,201 @@
+use std::io;
+use std::io::{Read, Write};
+
+use Float;
+use encoding::event::Event;
+use export::bytes::{put_u16, put_u32, get, slice, invalid};
+use export::npy::{Array, spikes_bytes, states_bytes, parse_npy, write_npy};
+
+// NumPy .npz archives: a zip of .npy files, one per named array, as
+// written by `numpy.savez`. Entries are stored uncompressed.
+
+const LOCAL_HEADER: u32 = 0x04034b50;
+const CENTRAL_HEADER: u32 = 0x02014b50;
+const END_OF_CENTRAL: u32 = 0x06054b50;
+const ZIP64_EXTRA: u64 = 0x0001;
+
+// 1980-01-01, the earliest date a zip can hold
+const DOS_DATE: u16 = 0x21;
+
+pub struct Npz {
+  entries: Vec<(String, Vec<u8>)>,
+}
+
+impl Npz {
+  pub fn new() -> Npz {
+    Npz{
+      entries: Vec::new(),
+    }
+  }
+
+  pub fn add_spikes(&mut self, name: &str, events: &[Event]) {
+    self.entries.push((format!("{}.npy", name), spikes_bytes(events)));
+  }
+
+  pub fn add_states(&mut self, name: &str, states: &[Vec<Float>]) -> io::Result<()> {
+    let bytes = try!(states_bytes(states));
+    self.entries.push((format!("{}.npy", name), bytes));
+    Ok(())
+  }
+
+  pub fn add_array(&mut self, name: &str, shape: &[usize], data: &[Float]) -> io::Result<()> {
+    let mut bytes = Vec::new();
+    try!(write_npy(&mut bytes, shape, data));
+    self.entries.push((format!("{}.npy", name), bytes));
+    Ok(())
+  }
+
+  pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+    let mut archive = Vec::new();
+    let mut central = Vec::new();
+
+    for &(ref name, ref data) in self.entries.iter() {
+      let offset = archive.len() as u32;
+      let crc = crc32(data);
+
+      // shared by the local and central headers from the version needed
+      let mut common = Vec::new();
+      put_u16(&mut common, 20);
+      put_u16(&mut common, 0); // flags
+      put_u16(&mut common, 0); // stored
+      put_u16(&mut common, 0); // time
+      put_u16(&mut common, DOS_DATE);
+      put_u32(&mut common, crc);
+      put_u32(&mut common, data.len() as u32);
+      put_u32(&mut common, data.len() as u32);
+      put_u16(&mut common, name.len() as u16);
+      put_u16(&mut common, 0); // extra field length
+
+      put_u32(&mut archive, LOCAL_HEADER);
+      archive.extend(common.iter());
+      archive.extend(name.as_bytes().iter());
+      archive.extend(data.iter());
+
+      put_u32(&mut central, CENTRAL_HEADER);
+      put_u16(&mut central, 20); // version made by
+      central.extend(common.iter());
+      put_u16(&mut central, 0); // comment length
+      put_u16(&mut central, 0); // disk
+      put_u16(&mut central, 0); // internal attributes
+      put_u32(&mut central, 0); // external attributes
+      put_u32(&mut central, offset);
+      central.extend(name.as_bytes().iter());
+    }
+
+    let central_offset = archive.len() as u32;
+    archive.extend(central.iter());
+
+    put_u32(&mut archive, END_OF_CENTRAL);
+    put_u16(&mut archive, 0);
+    put_u16(&mut archive, 0);
+    put_u16(&mut archive, self.entries.len() as u16);
+    put_u16(&mut archive, self.entries.len() as u16);
+    put_u32(&mut archive, central.len() as u32);
+    put_u32(&mut archive, central_offset);
+    put_u16(&mut archive, 0);
+
+    writer.write_all(&archive)
+  }
+}
+
+// The arrays of an archive by name, without the .npy extension. Only
+// stored entries are supported, so not those of `numpy.savez_compressed`.
+pub fn read_npz<R: Read>(mut reader: R) -> io::Result<Vec<(String, Array)>> {
+  let mut data = Vec::new();
+  try!(reader.read_to_end(&mut data));
+
+  // the end of central directory record, searched for from the back to
+  // skip any archive comment
+  let mut end = None;
+  if data.len() >= 22 {
+    for i in (0..data.len() - 21).rev() {
+      if get(&data[i..i + 4]) == END_OF_CENTRAL as u64 {
+        end = Some(i);
+        break;
+      }
+    }
+  }
+  let end = try!(end.ok_or(invalid("not a zip archive")));
+
+  let count = get(try!(slice(&data, end + 10, 2))) as usize;
+  let mut offset = get(try!(slice(&data, end + 16, 4))) as usize;
+
+  let mut arrays = Vec::with_capacity(count);
+  for _ in 0..count {
+    let header = try!(slice(&data, offset, 46));
+    if get(&header[..4]) != CENTRAL_HEADER as u64 {
+      return Err(invalid("invalid zip central directory"));
+    }
+
+    let method = get(&header[10..12]);
+    let crc = get(&header[16..20]) as u32;
+    let mut size = get(&header[20..24]);
+    let name_len = get(&header[28..30]) as usize;
+    let extra_len = get(&header[30..32]) as usize;
+    let comment_len = get(&header[32..34]) as usize;
+    let mut local = get(&header[42..46]);
+
+    let name = try!(String::from_utf8(try!(slice(&data, offset + 46, name_len)).to_vec())
+      .map_err(|_| invalid("invalid zip entry name")));
+
+    // zip64 sizes and offset replace the saturated fields, in order
+    let extra = try!(slice(&data, offset + 46 + name_len, extra_len));
+    let uncompressed = get(&header[24..28]);
+    let mut i = 0;
+    while i + 4 <= extra.len() {
+      let id = get(&extra[i..i + 2]);
+      let len = get(&extra[i + 2..i + 4]) as usize;
+      if id == ZIP64_EXTRA {
+        let mut fields = try!(slice(extra, i + 4, len)).chunks(8);
+        if uncompressed == 0xffffffff {
+          fields.next();
+        }
+        if size == 0xffffffff {
+          size = get(try!(fields.next().ok_or(invalid("invalid zip64 field"))));
+        }
+        if local == 0xffffffff {
+          local = get(try!(fields.next().ok_or(invalid("invalid zip64 field"))));
+        }
+      }
+      i += 4 + len;
+    }
+
+    if method != 0 {
+      return Err(invalid("compressed npz entries are not supported"));
+    }
+
+    // the local header's name and extra field may differ from the central
+    let local = local as usize;
+    let local_header = try!(slice(&data, local, 30));
+    if get(&local_header[..4]) != LOCAL_HEADER as u64 {
+      return Err(invalid("invalid zip local header"));
+    }
+    let start = local + 30 + get(&local_header[26..28]) as usize + get(&local_header[28..30]) as usize;
+    let bytes = try!(slice(&data, start, size as usize));
+    if crc32(bytes) != crc {
+      return Err(invalid("zip entry checksum mismatch"));
+    }
+
+    let name = if name.ends_with(".npy") {
+      name[..name.len() - 4].to_string()
+    } else {
+      name
+    };
+    arrays.push((name, try!(parse_npy(bytes))));
+
+    offset += 46 + name_len + extra_len + comment_len;
+  }
+  Ok(arrays)
+}
+
+// CRC-32 with the reflected polynomial 0xedb88320, as used by zip.
+fn crc32(data: &[u8]) -> u32 {
+  let mut crc = 0xffffffffu32;
+  for &b in data.iter() {
+    crc ^= b as u32;
+    for _ in 0..8 {
+      crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+    }
+  }
+  !crc
+}
@@ -0,0 +1,32 @@
+use Float;
+use std::default::Default;
+
+// Multiplicative synaptic scaling (Turrigiano et al. 1998). Rates are in
+// spikes per tick and times in ticks.
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct HomeostasisConfig {
+  // firing rate each neuron is driven towards
+  pub target_rate: Float,
+
+  // time constant of the running firing rate estimate
+  pub tau_rate: Float,
+
+  // fractional weight change per update at zero activity
+  pub eta: Float,
+
+  // ticks between scaling updates
+  pub period: usize,
+}
+
+impl Default for HomeostasisConfig {
+  fn default() -> HomeostasisConfig {
+    HomeostasisConfig{
+      target_rate: 0.005,
+      tau_rate: 1000.0,
+      eta: 0.01,
+      period: 100,
+    }
+  }
+}
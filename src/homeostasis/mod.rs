@@ -0,0 +1,5 @@
+pub use self::config::HomeostasisConfig;
+pub use self::population::Population;
+
+pub mod config;
+pub mod population;
@@ -0,0 +1,88 @@
+extern crate vec_map;
+
+use self::vec_map::VecMap;
+
+use Float;
+use homeostasis::config::HomeostasisConfig;
+
+// A group of neurons sharing a homeostatic set point. Keeps a running
+// estimate of each neuron's firing rate and the factor its incoming
+// excitatory weights should be scaled by.
+pub struct Population {
+  neuron_ids: Vec<usize>,
+
+  // index into `neuron_ids` and `rates` by neuron id
+  indices: VecMap<usize>,
+
+  rates: Vec<Float>,
+
+  target_rate: Float,
+  decay: Float,
+  tau_rate: Float,
+  eta: Float,
+  period: usize,
+
+  ticks: usize,
+}
+
+impl Population {
+  pub fn new(neuron_ids: &[usize], config: HomeostasisConfig) -> Population {
+    let mut indices = VecMap::new();
+    for (i, &neuron_id) in neuron_ids.iter().enumerate() {
+      indices.insert(neuron_id, i);
+    }
+
+    Population{
+      neuron_ids: neuron_ids.to_vec(),
+      indices: indices,
+      // start at the set point so nothing scales before the estimate
+      // has seen any activity
+      rates: vec![config.target_rate; neuron_ids.len()],
+      target_rate: config.target_rate,
+      decay: (-1.0 / config.tau_rate).exp(),
+      tau_rate: config.tau_rate,
+      eta: config.eta,
+      period: config.period,
+      ticks: 0,
+    }
+  }
+
+  pub fn neuron_ids(&self) -> &[usize] {
+    &self.neuron_ids
+  }
+
+  pub fn rate(&self, neuron_id: usize) -> Option<Float> {
+    self.indices.get(&neuron_id).map(|&i| self.rates[i])
+  }
+
+  // Advance the rate estimates by one tick given the neurons that fired.
+  // Returns true when a scaling update is due.
+  pub fn observe(&mut self, spiked: &[usize]) -> bool {
+    for rate in self.rates.iter_mut() {
+      *rate *= self.decay;
+    }
+
+    for neuron_id in spiked.iter() {
+      if let Some(&i) = self.indices.get(neuron_id) {
+        self.rates[i] += 1.0 / self.tau_rate;
+      }
+    }
+
+    self.ticks += 1;
+    if self.ticks < self.period {
+      return false;
+    }
+    self.ticks = 0;
+    true
+  }
+
+  // Scaling factor for the `i`th neuron's incoming excitatory weights.
+  pub fn factor(&self, i: usize) -> Float {
+    let factor = 1.0 + self.eta * (1.0 - self.rates[i] / self.target_rate);
+    if factor < 0.0 {
+      0.0
+    } else {
+      factor
+    }
+  }
+}
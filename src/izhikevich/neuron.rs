@@ -17,6 +17,15 @@ pub struct IzhikevichNeuron {
   // Describes accumulated membrane potential before updating.
   i: Float,
 
+  // Input carried over into the next tick by spikes arriving part-way
+  // through the current one.
+  i_next: Float,
+
+  // Total input conductance and the conductance-weighted reversal
+  // potential, so g(E - v) summed over channels is `g_e - g * v`.
+  g: Float,
+  g_e: Float,
+
   // Describes the time scale of the recovery variable `u`.
   // Smaller values result in slower recovery.
   // A typical value is `a = 0.02`.
@@ -70,6 +79,9 @@ impl IzhikevichNeuron {
       is_accomodation: config.is_accomodation,
       tau: tau,
       i: 0.0,
+      i_next: 0.0,
+      g: 0.0,
+      g_e: 0.0,
     }
   }
 }
@@ -80,6 +92,21 @@ impl Neuron for IzhikevichNeuron {
         self.i
     }
 
+    fn recv_at(&mut self, v: Float, offset: Float) -> Float {
+        // A spike is a unit pulse of current. Arriving at `offset` it
+        // overlaps this tick by `1 - offset` and the next by `offset`, so
+        // split its charge between the two.
+        self.i += v * (1.0 - offset);
+        self.i_next += v * offset;
+        self.i
+    }
+
+    fn recv_conductance(&mut self, g: Float, e_rev: Float) -> Float {
+        self.g += g;
+        self.g_e += g * e_rev;
+        self.g_e - self.g * self.v
+    }
+
     fn threshold(&mut self) -> Float {
         if self.v >= V_PEAK {
             V_PEAK
@@ -88,6 +115,10 @@ impl Neuron for IzhikevichNeuron {
         }
     }
 
+    fn voltage(&self) -> Float {
+        self.v
+    }
+
     fn reset(&mut self) {
         self.v = self.c;
         self.u += self.d;
@@ -97,8 +128,10 @@ impl Neuron for IzhikevichNeuron {
         let tau_count = (tau / self.tau) as usize;
         for _ in 0..tau_count {
             // The potential updates according to the input and the
-            // passage of time including the variable recovery factor
-            self.v += self.tau * (0.04 * (self.v * self.v) + self.e * self.v + self.f - self.u + self.i);
+            // passage of time including the variable recovery factor.
+            // The conductance term is integrated implicitly so large
+            // conductances stay stable.
+            self.v = (self.v + self.tau * (0.04 * (self.v * self.v) + self.e * self.v + self.f - self.u + self.i + self.g_e)) / (1.0 + self.tau * self.g);
 
             // The recovery factor is updated according to the current
             // potential and itself.
@@ -109,6 +142,9 @@ impl Neuron for IzhikevichNeuron {
             };
         }
 
-        self.i = 0.0;
+        self.i = self.i_next;
+        self.i_next = 0.0;
+        self.g = 0.0;
+        self.g_e = 0.0;
     }
 }
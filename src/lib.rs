@@ -13,9 +13,31 @@ pub mod neuron;
 pub mod synapse;
 pub mod trace;
 
+pub mod aer;
+pub mod bptt;
+pub mod clopath;
+pub mod conductance;
+pub mod connectivity;
+pub mod conversion;
+pub mod decoding;
+pub mod encoding;
+pub mod export;
+pub mod homeostasis;
 pub mod izhikevich;
+pub mod lif;
+pub mod neuroml;
+pub mod psc;
+pub mod rate;
+pub mod readout;
+pub mod receptor;
+pub mod resume;
+pub mod rstdp;
 pub mod stdp;
+pub mod stp;
+pub mod structural;
 pub mod sym;
 pub mod traces;
+pub mod triplet;
+pub mod vogels;
 
 mod spike;
@@ -0,0 +1,61 @@
+use Float;
+use std::default::Default;
+
+// Leaky integrate-and-fire with an adaptive threshold. Presets follow the
+// excitatory and inhibitory neurons of Diehl & Cook (2015), "Unsupervised
+// learning of digit recognition using spike-timing-dependent plasticity".
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct LIFConfig {
+  pub v_rest: Float,
+  pub v_reset: Float,
+  pub v_thresh: Float,
+
+  // membrane time constant
+  pub tau_m: Float,
+
+  // ticks after a spike during which input is ignored
+  pub refractory: Float,
+
+  // threshold increase per spike and its decay time constant
+  pub theta_plus: Float,
+  pub tau_theta: Float,
+
+  // whether the threshold adapts, turned off to freeze it for evaluation
+  pub adaptive: bool,
+}
+
+impl Default for LIFConfig {
+  fn default() -> LIFConfig {
+    LIFConfig::excitatory()
+  }
+}
+
+impl LIFConfig {
+  pub fn excitatory() -> LIFConfig {
+    LIFConfig{
+      v_rest: -65.0,
+      v_reset: -65.0,
+      v_thresh: -52.0,
+      tau_m: 100.0,
+      refractory: 5.0,
+      theta_plus: 0.05,
+      tau_theta: 1e7,
+      adaptive: true,
+    }
+  }
+
+  pub fn inhibitory() -> LIFConfig {
+    LIFConfig{
+      v_rest: -60.0,
+      v_reset: -45.0,
+      v_thresh: -40.0,
+      tau_m: 10.0,
+      refractory: 2.0,
+      theta_plus: 0.0,
+      tau_theta: 1e7,
+      adaptive: false,
+    }
+  }
+}
@@ -0,0 +1,5 @@
+pub use self::config::LIFConfig;
+pub use self::neuron::LIFNeuron;
+
+pub mod config;
+pub mod neuron;
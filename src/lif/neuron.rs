@@ -0,0 +1,137 @@
+use Float;
+use std::default::Default;
+
+use neuron::Neuron;
+use lif::config::LIFConfig;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LIFNeuron {
+  // Membrane potential
+  pub v: Float,
+
+  // Adaptive part of the threshold, added to `v_thresh`.
+  pub theta: Float,
+
+  v_rest: Float,
+  v_reset: Float,
+  v_thresh: Float,
+  tau_m: Float,
+
+  refractory: Float,
+
+  // Time left in the current refractory period.
+  refractory_left: Float,
+
+  theta_plus: Float,
+  tau_theta: Float,
+  adaptive: bool,
+
+  // Input current for the next tick and the part of it carried over by
+  // spikes arriving part-way through the current one.
+  i: Float,
+  i_next: Float,
+
+  // Total input conductance and the conductance-weighted reversal
+  // potential.
+  g: Float,
+  g_e: Float,
+
+  tau: Float,
+}
+
+impl Default for LIFNeuron {
+  fn default() -> LIFNeuron {
+    LIFNeuron::new(0.5, Default::default())
+  }
+}
+
+impl LIFNeuron {
+  pub fn new(tau: Float, config: LIFConfig) -> LIFNeuron {
+    LIFNeuron{
+      v: config.v_rest,
+      theta: 0.0,
+      v_rest: config.v_rest,
+      v_reset: config.v_reset,
+      v_thresh: config.v_thresh,
+      tau_m: config.tau_m,
+      refractory: config.refractory,
+      refractory_left: 0.0,
+      theta_plus: config.theta_plus,
+      tau_theta: config.tau_theta,
+      adaptive: config.adaptive,
+      i: 0.0,
+      i_next: 0.0,
+      g: 0.0,
+      g_e: 0.0,
+      tau: tau,
+    }
+  }
+
+  // Freezes (or resumes) threshold adaptation, keeping the current `theta`.
+  pub fn toggle_adaptation(&mut self, enabled: bool) {
+    self.adaptive = enabled;
+  }
+}
+
+impl Neuron for LIFNeuron {
+  fn recv(&mut self, v: Float) -> Float {
+    self.i += v;
+    self.i
+  }
+
+  fn recv_at(&mut self, v: Float, offset: Float) -> Float {
+    self.i += v * (1.0 - offset);
+    self.i_next += v * offset;
+    self.i
+  }
+
+  fn recv_conductance(&mut self, g: Float, e_rev: Float) -> Float {
+    self.g += g;
+    self.g_e += g * e_rev;
+    self.g_e - self.g * self.v
+  }
+
+  // Spikes are unit events; the membrane potential itself never peaks.
+  fn threshold(&mut self) -> Float {
+    if self.v >= self.v_thresh + self.theta {
+      1.0
+    } else {
+      0.0
+    }
+  }
+
+  fn voltage(&self) -> Float {
+    self.v
+  }
+
+  fn reset(&mut self) {
+    self.v = self.v_reset;
+    self.refractory_left = self.refractory;
+    if self.adaptive {
+      self.theta += self.theta_plus;
+    }
+  }
+
+  fn tick(&mut self, tau: Float) {
+    let tau_count = (tau / self.tau) as usize;
+    for _ in 0..tau_count {
+      if self.refractory_left > 0.0 {
+        self.refractory_left -= self.tau;
+        continue;
+      }
+
+      // Leak towards rest plus input, with the conductance term
+      // integrated implicitly.
+      self.v = (self.v + self.tau * ((self.v_rest - self.v) / self.tau_m + self.i + self.g_e)) / (1.0 + self.tau * self.g);
+    }
+
+    if self.adaptive {
+      self.theta *= (-tau / self.tau_theta).exp();
+    }
+
+    self.i = self.i_next;
+    self.i_next = 0.0;
+    self.g = 0.0;
+    self.g_e = 0.0;
+  }
+}
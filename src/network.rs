@@ -8,11 +8,20 @@ use Float;
 
 use neuron::Neuron;
 use synapse::Synapse;
-use spike::Spike;
+use receptor::Receptors;
+use spike::{Spike, SpikeKind};
+use homeostasis::{HomeostasisConfig, Population};
+use structural::{StructuralConfig, StructuralRule, StructuralReport};
+use connectivity::{Connection, SparseMatrix};
+use std::mem;
 
 #[derive(Debug)]
 pub enum NeuralError {
     MissingNeuron = 0,
+    MissingSynapse = 1,
+    InvalidDelay = 2,
+    InvalidShape = 3,
+    InvalidConfig = 4,
 }
 
 pub struct Network<N: Neuron, S: Synapse> {
@@ -22,8 +31,36 @@ pub struct Network<N: Neuron, S: Synapse> {
     send_synapses: VecMap<Vec<(usize, usize)>>,
     recv_synapses: VecMap<Vec<usize>>,
 
+    // (sendr_id, recvr_id) of every synapse
+    synapse_ends: VecMap<(usize, usize)>,
+
+    // incoming synapses that follow the postsynaptic voltage
+    voltage_synapses: VecMap<Vec<usize>>,
+
+    // electrical synapses as (a, b, conductance)
+    gap_junctions: Vec<(usize, usize, Float)>,
+    gap_currents: VecMap<Float>,
+
+    // populations under homeostatic scaling
+    populations: Vec<Population>,
+
+    // neurons that fired during the current tick
+    spiked: Vec<usize>,
+
+    // teacher spike times still to come per neuron, latest first
+    target_spikes: VecMap<Vec<Float>>,
+
+    // structural plasticity rules, neuron positions they use to limit
+    // growth by distance, and the changes they have made
+    structural_rules: Vec<StructuralRule<S>>,
+    positions: VecMap<[Float; 3]>,
+    structural_report: StructuralReport,
+
     scheduler: wheel_timer::WheelTimer<Spike>,
 
+    // learning events due later within the current tick
+    pending: Vec<Spike>,
+
     next_neuron_id: usize,
     next_synapse_id: usize,
 
@@ -40,7 +77,18 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
             synapses: VecMap::new(),
             send_synapses: VecMap::new(),
             recv_synapses: VecMap::new(),
+            synapse_ends: VecMap::new(),
+            voltage_synapses: VecMap::new(),
+            gap_junctions: Vec::new(),
+            gap_currents: VecMap::new(),
+            populations: Vec::new(),
+            spiked: Vec::new(),
+            target_spikes: VecMap::new(),
+            structural_rules: Vec::new(),
+            positions: VecMap::new(),
+            structural_report: Default::default(),
             scheduler: wheel_timer::WheelTimer::new(max_delay),
+            pending: Vec::new(),
             next_neuron_id: 0,
             next_synapse_id: 0,
             transmission_enabled: true,
@@ -57,6 +105,14 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
         self.synapses.len()
     }
 
+    pub fn get_neuron(&self, neuron_id: usize) -> Option<&N> {
+        self.neurons.get(&neuron_id)
+    }
+
+    pub fn get_neuron_mut(&mut self, neuron_id: usize) -> Option<&mut N> {
+        self.neurons.get_mut(&neuron_id)
+    }
+
     pub fn add_neuron(&mut self, neuron: N) -> usize {
         let neuron_id = self.next_neuron_id;
         self.next_neuron_id = neuron_id + 1;
@@ -70,11 +126,21 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
             return Err(NeuralError::MissingNeuron)
         }
 
+        // Spikes are delivered no sooner than the next tick, and the
+        // dendritic part of the delay is a fraction of it.
+        let delay = synapse.delay();
+        let dendritic_delay = synapse.dendritic_delay();
+        if !(delay >= 1.0) || !(dendritic_delay >= 0.0 && dendritic_delay <= delay) {
+            return Err(NeuralError::InvalidDelay)
+        }
+
         // sendr_id (pre) -> (post) recvr_id
         let synapse_id = self.next_synapse_id;
         self.next_synapse_id = synapse_id + 1;
 
+        let is_voltage_dependent = synapse.is_voltage_dependent();
         self.synapses.insert(synapse_id, synapse);
+        self.synapse_ends.insert(synapse_id, (sendr_id, recvr_id));
 
         let send_synapses = match self.send_synapses.entry(sendr_id) {
             Vacant(entry) => entry.insert(Vec::new()),
@@ -90,15 +156,420 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
         recv_synapses.push(synapse_id);
         // println!("recv: recv_id: {:?} recv_synapses: {:?}", recvr_id, recv_synapses.len());
 
+        if is_voltage_dependent {
+            let voltage_synapses = match self.voltage_synapses.entry(recvr_id) {
+                Vacant(entry) => entry.insert(Vec::new()),
+                Occupied(entry) => entry.into_mut(),
+            };
+            voltage_synapses.push(synapse_id);
+        }
+
         Ok(synapse_id)
     }
 
+    // Removes a synapse. Ids are never reused so the remaining synapses
+    // keep theirs. Spikes already in flight are still delivered.
+    pub fn remove_synapse(&mut self, synapse_id: usize) -> Option<S> {
+        let (sendr_id, recvr_id) = match self.synapse_ends.remove(&synapse_id) {
+            Some(ends) => ends,
+            None => return None,
+        };
+
+        if let Some(send_synapses) = self.send_synapses.get_mut(&sendr_id) {
+            send_synapses.retain(|&(_, id)| id != synapse_id);
+        }
+        if let Some(recv_synapses) = self.recv_synapses.get_mut(&recvr_id) {
+            recv_synapses.retain(|&id| id != synapse_id);
+        }
+        if let Some(voltage_synapses) = self.voltage_synapses.get_mut(&recvr_id) {
+            voltage_synapses.retain(|&id| id != synapse_id);
+        }
+        for rule in self.structural_rules.iter_mut() {
+            rule.forget(synapse_id);
+        }
+
+        self.synapses.remove(&synapse_id)
+    }
+
+    pub fn is_connected(&self, sendr_id: usize, recvr_id: usize) -> bool {
+        match self.send_synapses.get(&sendr_id) {
+            Some(send_synapses) => send_synapses.iter().any(|&(id, _)| id == recvr_id),
+            None => false,
+        }
+    }
+
+    // Places a neuron in space for distance-limited structural plasticity.
+    pub fn set_position(&mut self, neuron_id: usize, position: [Float; 3]) -> Result<(), NeuralError> {
+        if !self.neurons.contains_key(&neuron_id) {
+            return Err(NeuralError::MissingNeuron)
+        }
+
+        self.positions.insert(neuron_id, position);
+        Ok(())
+    }
+
+    fn distance(&self, a: usize, b: usize) -> Option<Float> {
+        match (self.positions.get(&a), self.positions.get(&b)) {
+            (Some(p), Some(q)) => {
+                let d2 = (0..3).fold(0.0, |d2, i| d2 + (p[i] - q[i]) * (p[i] - q[i]));
+                Some(d2.sqrt())
+            },
+            _ => None,
+        }
+    }
+
+    // Periodically prunes weak synapses from `sources` to `targets` and
+    // grows new ones between unconnected pairs. Runs with learning.
+    pub fn add_structural_plasticity(&mut self, sources: &[usize], targets: &[usize], config: StructuralConfig, factory: Box<Fn(usize, usize) -> S>) -> Result<usize, NeuralError> {
+        if sources.iter().chain(targets.iter()).any(|id| !self.neurons.contains_key(id)) {
+            return Err(NeuralError::MissingNeuron)
+        }
+
+        let rule_id = self.structural_rules.len();
+        self.structural_rules.push(StructuralRule::new(sources, targets, config, factory));
+        Ok(rule_id)
+    }
+
+    // Synapses created and pruned since the last call.
+    pub fn take_structural_report(&mut self) -> StructuralReport {
+        mem::replace(&mut self.structural_report, Default::default())
+    }
+
+    fn update_structure(&mut self) {
+        // The rules add and remove synapses through `self`, so they are
+        // out of `self.structural_rules` meanwhile.
+        let mut rules = mem::replace(&mut self.structural_rules, Vec::new());
+
+        // Pairs pruned in this update may not regrow until the next one.
+        let mut pruned = Vec::new();
+
+        for r in 0..rules.len() {
+            // Weakness is tracked every tick but only acted on each period.
+            let due = rules[r].step();
+
+            for t in 0..rules[r].targets().len() {
+                let recvr_id = rules[r].targets()[t];
+                let recv_synapses = match self.recv_synapses.get(&recvr_id) {
+                    Some(recv_synapses) => recv_synapses.clone(),
+                    None => Vec::new(),
+                };
+
+                for &synapse_id in recv_synapses.iter() {
+                    let sendr_id = match self.synapse_ends.get(&synapse_id) {
+                        Some(&(sendr_id, _)) => sendr_id,
+                        None => continue,
+                    };
+                    if !rules[r].is_source(sendr_id) {
+                        continue;
+                    }
+
+                    let weight = match self.synapses.get(&synapse_id) {
+                        Some(synapse) => synapse.weight(),
+                        None => continue,
+                    };
+                    rules[r].track(synapse_id, weight, self.now);
+                    if due && rules[r].should_prune(synapse_id, self.now) {
+                        self.remove_synapse(synapse_id);
+                        for rule in rules.iter_mut() {
+                            rule.forget(synapse_id);
+                        }
+                        pruned.push((sendr_id, recvr_id));
+                        self.structural_report.pruned.push(synapse_id);
+                    }
+                }
+
+                if !due {
+                    continue;
+                }
+
+                for s in 0..rules[r].sources().len() {
+                    let sendr_id = rules[r].sources()[s];
+                    if sendr_id == recvr_id || self.is_connected(sendr_id, recvr_id) || pruned.contains(&(sendr_id, recvr_id)) {
+                        continue;
+                    }
+
+                    let distance = self.distance(sendr_id, recvr_id);
+                    if rules[r].should_grow(distance) {
+                        let synapse = rules[r].create(sendr_id, recvr_id);
+                        if let Ok(synapse_id) = self.add_synapse(synapse, sendr_id, recvr_id) {
+                            self.structural_report.created.push(synapse_id);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.structural_rules = rules;
+    }
+
+    // Couples two neurons electrically. Every tick each receives a current
+    // of g(v_other - v_self), bypassing the spike scheduler.
+    pub fn add_gap_junction(&mut self, a: usize, b: usize, g: Float) -> Result<usize, NeuralError> {
+        if !self.neurons.contains_key(&a) || !self.neurons.contains_key(&b) {
+            return Err(NeuralError::MissingNeuron)
+        }
+
+        let gap_junction_id = self.gap_junctions.len();
+        self.gap_junctions.push((a, b, g));
+        Ok(gap_junction_id)
+    }
+
+    // Scales the excitatory weights received by a population towards a
+    // target firing rate. Runs with learning.
+    pub fn add_homeostasis(&mut self, neuron_ids: &[usize], config: HomeostasisConfig) -> Result<usize, NeuralError> {
+        if neuron_ids.iter().any(|id| !self.neurons.contains_key(id)) {
+            return Err(NeuralError::MissingNeuron)
+        }
+
+        let population_id = self.populations.len();
+        self.populations.push(Population::new(neuron_ids, config));
+        Ok(population_id)
+    }
+
+    // Writes each synapse's weight at its id. Ids past the end of
+    // `weights` are skipped.
     pub fn dump_weights(&self, weights: &mut [Float]) {
         for (i, s) in self.synapses.iter() {
-            weights[i] = s.weight();
+            if let Some(weight) = weights.get_mut(i) {
+                *weight = s.weight();
+            }
+        }
+    }
+
+    // Sets each synapse's weight from its id, the counterpart of
+    // `dump_weights`. Ids past the end of `weights` are left unchanged.
+    pub fn load_weights(&mut self, weights: &[Float]) {
+        for (i, s) in self.synapses.iter_mut() {
+            if let Some(&weight) = weights.get(i) {
+                s.set_weight(weight);
+            }
         }
     }
 
+    // Every synapse as a (pre, post, weight, delay) connection, in order
+    // of synapse id.
+    pub fn connections(&self) -> Vec<Connection> {
+        self.synapses.iter().map(|(synapse_id, synapse)| {
+            let (pre, post) = self.synapse_ends[synapse_id];
+            Connection{
+                pre: pre,
+                post: post,
+                weight: synapse.weight(),
+                delay: synapse.delay(),
+            }
+        }).collect()
+    }
+
+    // Adds a synapse for every connection, built by `factory`.
+    pub fn add_connections<F: Fn(&Connection) -> S>(&mut self, connections: &[Connection], factory: F) -> Result<Vec<usize>, NeuralError> {
+        for connection in connections.iter() {
+            if !self.neurons.contains_key(&connection.pre) || !self.neurons.contains_key(&connection.post) {
+                return Err(NeuralError::MissingNeuron)
+            }
+        }
+
+        let mut synapse_ids = Vec::with_capacity(connections.len());
+        for connection in connections.iter() {
+            synapse_ids.push(try!(self.add_synapse(factory(connection), connection.pre, connection.post)));
+        }
+        Ok(synapse_ids)
+    }
+
+    // Sets the weight of the synapses between each connection's neurons,
+    // leaving delays unchanged. Nothing changes unless every connection
+    // exists.
+    pub fn set_connection_weights(&mut self, connections: &[Connection]) -> Result<(), NeuralError> {
+        if connections.iter().any(|c| !self.is_connected(c.pre, c.post)) {
+            return Err(NeuralError::MissingSynapse)
+        }
+
+        for connection in connections.iter() {
+            self.set_pair_weight(connection.pre, connection.post, connection.weight);
+        }
+        Ok(())
+    }
+
+    // Dense row-major weights from `pre_ids` (rows) to `post_ids`
+    // (columns). Unconnected pairs are zero and parallel synapses sum.
+    pub fn weight_matrix(&self, pre_ids: &[usize], post_ids: &[usize]) -> Vec<Float> {
+        let mut weights = vec![0.0; pre_ids.len() * post_ids.len()];
+        for (r, c, w) in self.pair_weights(pre_ids, post_ids) {
+            weights[r * post_ids.len() + c] += w;
+        }
+        weights
+    }
+
+    // As `weight_matrix`, keeping only existing synapses.
+    pub fn sparse_weight_matrix(&self, pre_ids: &[usize], post_ids: &[usize]) -> SparseMatrix {
+        let mut weights = self.pair_weights(pre_ids, post_ids);
+        weights.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        let mut matrix = SparseMatrix{
+            rows: pre_ids.len(),
+            cols: post_ids.len(),
+            indptr: vec![0],
+            indices: Vec::with_capacity(weights.len()),
+            data: Vec::with_capacity(weights.len()),
+        };
+
+        let mut i = 0;
+        for r in 0..pre_ids.len() {
+            let mut last = None;
+            while i < weights.len() && weights[i].0 == r {
+                let (_, c, w) = weights[i];
+                // parallel synapses sum
+                if last == Some(c) {
+                    *matrix.data.last_mut().unwrap() += w;
+                } else {
+                    matrix.indices.push(c);
+                    matrix.data.push(w);
+                    last = Some(c);
+                }
+                i += 1;
+            }
+            matrix.indptr.push(matrix.indices.len());
+        }
+        matrix
+    }
+
+    // Sets the synapses between `pre_ids` and `post_ids` from a dense
+    // row-major matrix. Parallel synapses split their entry evenly, so
+    // the matrix `weight_matrix` returns sets them back. Zero entries
+    // of unconnected pairs are ignored, nonzero ones are an error and
+    // leave the network unchanged, as does a matrix of the wrong size.
+    pub fn set_weight_matrix(&mut self, pre_ids: &[usize], post_ids: &[usize], weights: &[Float]) -> Result<(), NeuralError> {
+        if weights.len() != pre_ids.len() * post_ids.len() {
+            return Err(NeuralError::InvalidShape)
+        }
+
+        let mut entries = Vec::new();
+        for (r, &pre) in pre_ids.iter().enumerate() {
+            for (c, &post) in post_ids.iter().enumerate() {
+                let w = weights[r * post_ids.len() + c];
+                if self.is_connected(pre, post) {
+                    entries.push((pre, post, w));
+                } else if w != 0.0 {
+                    return Err(NeuralError::MissingSynapse)
+                }
+            }
+        }
+
+        for &(pre, post, w) in entries.iter() {
+            self.split_pair_weight(pre, post, w);
+        }
+        Ok(())
+    }
+
+    // As `set_weight_matrix` for a sparse matrix, which must be valid and
+    // whose entries must all be connected.
+    pub fn set_sparse_weight_matrix(&mut self, pre_ids: &[usize], post_ids: &[usize], matrix: &SparseMatrix) -> Result<(), NeuralError> {
+        if matrix.rows != pre_ids.len() || matrix.cols != post_ids.len() || !matrix.is_valid() {
+            return Err(NeuralError::InvalidShape)
+        }
+
+        let entries = matrix.entries();
+        if entries.iter().any(|&(r, c, _)| !self.is_connected(pre_ids[r], post_ids[c])) {
+            return Err(NeuralError::MissingSynapse)
+        }
+
+        for &(r, c, w) in entries.iter() {
+            self.split_pair_weight(pre_ids[r], post_ids[c], w);
+        }
+        Ok(())
+    }
+
+    // (row, column, weight) of every synapse from `pre_ids` to `post_ids`.
+    fn pair_weights(&self, pre_ids: &[usize], post_ids: &[usize]) -> Vec<(usize, usize, Float)> {
+        let mut columns = VecMap::new();
+        for (c, &post) in post_ids.iter().enumerate() {
+            columns.insert(post, c);
+        }
+
+        let mut weights = Vec::new();
+        for (r, pre) in pre_ids.iter().enumerate() {
+            if let Some(send_synapses) = self.send_synapses.get(pre) {
+                for &(recvr_id, synapse_id) in send_synapses.iter() {
+                    if let (Some(&c), Some(synapse)) = (columns.get(&recvr_id), self.synapses.get(&synapse_id)) {
+                        weights.push((r, c, synapse.weight()));
+                    }
+                }
+            }
+        }
+        weights
+    }
+
+    fn set_pair_weight(&mut self, pre: usize, post: usize, weight: Float) {
+        if let Some(send_synapses) = self.send_synapses.get(&pre) {
+            for &(recvr_id, synapse_id) in send_synapses.iter() {
+                if recvr_id != post {
+                    continue;
+                }
+                if let Some(synapse) = self.synapses.get_mut(&synapse_id) {
+                    synapse.set_weight(weight);
+                }
+            }
+        }
+    }
+
+    // Shares `weight` evenly between the synapses from `pre` to `post`.
+    fn split_pair_weight(&mut self, pre: usize, post: usize, weight: Float) {
+        let synapse_ids: Vec<usize> = match self.send_synapses.get(&pre) {
+            Some(send_synapses) => send_synapses.iter()
+                .filter(|&&(recvr_id, _)| recvr_id == post)
+                .map(|&(_, synapse_id)| synapse_id)
+                .collect(),
+            None => return,
+        };
+
+        let share = weight / synapse_ids.len() as Float;
+        for synapse_id in synapse_ids.iter() {
+            if let Some(synapse) = self.synapses.get_mut(synapse_id) {
+                synapse.set_weight(share);
+            }
+        }
+    }
+
+    // release dopamine onto every synapse
+    pub fn modulate(&mut self, d: Float) {
+        if !self.learning_enabled {
+            return;
+        }
+
+        for (_, synapse) in self.synapses.iter_mut() {
+            synapse.modulate(d, self.now);
+        }
+    }
+
+    // release dopamine onto the synapses received by a population
+    pub fn modulate_population(&mut self, neuron_ids: &[usize], d: Float) {
+        if !self.learning_enabled {
+            return;
+        }
+
+        for neuron_id in neuron_ids.iter() {
+            if let Some(recv_synapses) = self.recv_synapses.get(neuron_id) {
+                for synapse_id in recv_synapses.iter() {
+                    if let Some(synapse) = self.synapses.get_mut(synapse_id) {
+                        synapse.modulate(d, self.now);
+                    }
+                }
+            }
+        }
+    }
+
+    // Sets the spike times a teacher wants from a neuron, replacing any
+    // still to come. Supervised synapses onto it learn from them.
+    pub fn set_target_spikes(&mut self, neuron_id: usize, times: &[Float]) -> Result<(), NeuralError> {
+        if !self.neurons.contains_key(&neuron_id) {
+            return Err(NeuralError::MissingNeuron)
+        }
+
+        let mut times = times.to_vec();
+        times.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        self.target_spikes.insert(neuron_id, times);
+        Ok(())
+    }
+
     // toggle synaptic transmission (learning continues)
     pub fn toggle_transmission(&mut self, enabled: bool) {
         self.transmission_enabled = enabled;
@@ -118,16 +589,80 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
         for current_tick in 0..ticks {
             let spikes = self.scheduler.tick();
             for spike in spikes.iter() {
-                if let Some(neuron) = self.neurons.get_mut(&spike.recvr_id) {
-                    neuron.recv(spike.v);
+                match spike.kind {
+                    SpikeKind::Transmit => {
+                        if let Some(neuron) = self.neurons.get_mut(&spike.recvr_id) {
+                            neuron.recv_receptors(spike.v, spike.offset, spike.receptors);
+                        }
+                    },
+                    _ => {
+                        // Arrivals at the start of the tick precede any
+                        // spikes they cause, later ones wait for the
+                        // neuron updates.
+                        if spike.offset == 0.0 {
+                            learn(&mut self.synapses, spike, self.now, self.learning_enabled);
+                        } else {
+                            self.pending.push(*spike);
+                        }
+                    },
+                }
+            }
+
+            self.spiked.clear();
+
+            // electrical coupling from the voltages at the start of the tick
+            self.gap_currents.clear();
+            if self.transmission_enabled {
+                for &(a, b, g) in self.gap_junctions.iter() {
+                    let v_a = self.neurons.get(&a).map_or(0.0, |n| n.voltage());
+                    let v_b = self.neurons.get(&b).map_or(0.0, |n| n.voltage());
+                    for &(id, i) in [(a, g * (v_b - v_a)), (b, g * (v_a - v_b))].iter() {
+                        match self.gap_currents.entry(id) {
+                            Vacant(entry) => { entry.insert(i); },
+                            Occupied(mut entry) => { *entry.get_mut() += i; },
+                        }
+                    }
                 }
             }
 
             // update neurons
             for (sendr_id, neuron) in self.neurons.iter_mut() {
-                neuron.recv(inputs[current_tick * neuron_count + sendr_id]);
+                let gap_current = self.gap_currents.get(&sendr_id).map_or(0.0, |&i| i);
+                neuron.recv(inputs[current_tick * neuron_count + sendr_id] + gap_current);
                 neuron.tick(1.0);
 
+                // Voltage-dependent synapses see the membrane potential
+                // before any reset
+                if self.learning_enabled {
+                    if let Some(voltage_synapses) = self.voltage_synapses.get(&sendr_id) {
+                        let voltage = neuron.voltage();
+                        for &synapse_id in voltage_synapses.iter() {
+                            if let Some(synapse) = self.synapses.get_mut(&synapse_id) {
+                                synapse.post_voltage(voltage, self.now);
+                            }
+                        }
+                    }
+                }
+
+                // Teacher spikes due this tick
+                if let Some(target_spikes) = self.target_spikes.get_mut(&sendr_id) {
+                    let now = self.now;
+                    while target_spikes.last().map_or(false, |&t| t < now + 1.0) {
+                        let t = target_spikes.pop().unwrap();
+                        if !self.learning_enabled || t < now {
+                            continue;
+                        }
+
+                        if let Some(recv_synapses) = self.recv_synapses.get(&sendr_id) {
+                            for synapse_id in recv_synapses.iter() {
+                                if let Some(synapse) = self.synapses.get_mut(synapse_id) {
+                                    synapse.teach(t);
+                                }
+                            }
+                        }
+                    }
+                }
+
                 let v = neuron.threshold();
                 if v <= 0.0 {
                     continue;
@@ -135,14 +670,29 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
 
                 outputs[sendr_id] += v;
                 neuron.reset();
+                self.spiked.push(sendr_id);
 
                 // On the incoming (receiving synapses), update them post-receival
+                // once the spike has travelled back up the dendrite
                 if self.learning_enabled {
                     if let Some(recv_synapses) = self.recv_synapses.get_mut(&sendr_id) {
                         // println!("recv_synapses: sendr_id: {:?} recv_synapses: {:?}", sendr_id, recv_synapses.len());
-                        for synapse_id in recv_synapses.iter() {
+                        for &synapse_id in recv_synapses.iter() {
                             if let Some(synapse) = self.synapses.get_mut(&synapse_id) {
-                                synapse.post_recv(self.now);
+                                let dendritic_delay = synapse.dendritic_delay();
+                                if dendritic_delay == 0.0 {
+                                    synapse.post_recv(self.now);
+                                } else {
+                                    let spike = Spike{
+                                        kind:       SpikeKind::Post,
+                                        synapse_id: synapse_id,
+                                        recvr_id:   sendr_id,
+                                        v:          0.0,
+                                        receptors:  Receptors::default(),
+                                        offset:     0.0,
+                                    };
+                                    schedule(&mut self.scheduler, &mut self.pending, dendritic_delay, spike);
+                                }
                                 // post_recv_count += 1;
                             }
                         }
@@ -150,27 +700,76 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
                 }
 
                 // On the outgoing (sending synapses), update them pre-receival
+                // once the spike has travelled down the axon
                 if let Some(send_synapses) = self.send_synapses.get_mut(&sendr_id) {
                     // println!("send_synapses: sendr_id: {:?} send_synapses: {:?}", sendr_id, send_synapses.len());
                     for &(recvr_id, synapse_id) in send_synapses.iter() {
                         if let Some(synapse) = self.synapses.get_mut(&synapse_id) {
+                            let delay = synapse.delay();
+
                             if self.learning_enabled {
-                                synapse.pre_recv(self.now);
+                                // the weight sent must be current
+                                synapse.settle(self.now);
+
+                                let spike = Spike{
+                                    kind:       SpikeKind::Pre,
+                                    synapse_id: synapse_id,
+                                    recvr_id:   recvr_id,
+                                    v:          0.0,
+                                    receptors:  Receptors::default(),
+                                    offset:     0.0,
+                                };
+                                schedule(&mut self.scheduler, &mut self.pending, delay - synapse.dendritic_delay(), spike);
                                 // pre_recv_count += 1;
                             }
 
                             if self.transmission_enabled {
                                 let spike = Spike{
-                                    recvr_id: recvr_id,
-                                    v:        synapse.weight(),
+                                    kind:       SpikeKind::Transmit,
+                                    synapse_id: synapse_id,
+                                    recvr_id:   recvr_id,
+                                    v:          synapse.transmit(self.now),
+                                    receptors:  synapse.receptors(),
+                                    offset:     0.0,
                                 };
-                                self.scheduler.schedule(synapse.delay() - 1, spike);
+                                schedule(&mut self.scheduler, &mut self.pending, delay, spike);
                             }
                         }
                     }
                 }
             }
 
+            // learning events landing later within this tick, in time order
+            self.pending.sort_by(|a, b| a.offset.partial_cmp(&b.offset).unwrap());
+            for spike in self.pending.iter() {
+                learn(&mut self.synapses, spike, self.now, self.learning_enabled);
+            }
+            self.pending.clear();
+
+            // homeostatic scaling of incoming excitatory weights
+            for population in self.populations.iter_mut() {
+                if !population.observe(&self.spiked) || !self.learning_enabled {
+                    continue;
+                }
+
+                for (i, neuron_id) in population.neuron_ids().iter().enumerate() {
+                    let factor = population.factor(i);
+                    if let Some(recv_synapses) = self.recv_synapses.get(neuron_id) {
+                        for synapse_id in recv_synapses.iter() {
+                            if let Some(synapse) = self.synapses.get_mut(synapse_id) {
+                                if synapse.weight() > 0.0 {
+                                    synapse.scale(factor);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if self.learning_enabled && !self.structural_rules.is_empty() {
+                self.update_structure();
+            }
+
             self.now = self.now + 1.0;
         }
 
@@ -179,3 +778,32 @@ impl<N: Neuron, S: Synapse> Network<N, S> {
         self.now
     }
 }
+
+// Schedules `spike` to arrive `delay` ticks from now. Whole ticks go to the
+// scheduler and the remainder rides along as the offset. Arrivals within the
+// current tick are held in `pending`.
+fn schedule(scheduler: &mut wheel_timer::WheelTimer<Spike>, pending: &mut Vec<Spike>, delay: Float, mut spike: Spike) {
+    let ticks = delay.floor();
+    spike.offset = delay - ticks;
+    if ticks < 1.0 {
+        pending.push(spike);
+    } else {
+        scheduler.schedule(ticks as usize - 1, spike);
+    }
+}
+
+// Applies a learning event to its synapse at the exact arrival time.
+// Events still in flight when learning is turned off are dropped.
+fn learn<S: Synapse>(synapses: &mut VecMap<S>, spike: &Spike, now: Float, learning_enabled: bool) {
+    if !learning_enabled {
+        return;
+    }
+
+    if let Some(synapse) = synapses.get_mut(&spike.synapse_id) {
+        match spike.kind {
+            SpikeKind::Pre => { synapse.pre_recv(now + spike.offset); },
+            SpikeKind::Post => { synapse.post_recv(now + spike.offset); },
+            SpikeKind::Transmit => {},
+        }
+    }
+}
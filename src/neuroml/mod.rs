@@ -0,0 +1,10 @@
+pub use self::model::{Model, CellType, SynapseType, Population, Projection};
+pub use self::reader::{read_neuroml, open_neuroml};
+pub use self::writer::write_neuroml;
+
+pub mod model;
+pub mod reader;
+pub mod writer;
+
+mod units;
+mod xml;
@@ -0,0 +1,210 @@
+use std::io;
+
+use network::{Network, NeuralError};
+use neuron::Neuron;
+use synapse::Synapse;
+use connectivity::Connection;
+use izhikevich::IzhikevichConfig;
+use lif::LIFConfig;
+use stdp::STDPConfig;
+use sym::SymConfig;
+
+#[derive(Debug, Clone, Copy)]
+pub enum CellType {
+  Izhikevich(IzhikevichConfig),
+  LIF(LIFConfig),
+}
+
+// Static synapses keep the weight and delay of each connection; plastic
+// ones start from them.
+#[derive(Debug, Clone, Copy)]
+pub enum SynapseType {
+  Static,
+  STDP(STDPConfig),
+  Sym(SymConfig),
+}
+
+#[derive(Debug, Clone)]
+pub struct Population {
+  pub id: String,
+  pub cell: String,
+  pub size: usize,
+}
+
+// Connections between two populations, with `pre` and `post` indexing
+// into them rather than into a network.
+#[derive(Debug, Clone)]
+pub struct Projection {
+  pub id: String,
+  pub pre: String,
+  pub post: String,
+  pub synapse: String,
+  pub connections: Vec<Connection>,
+}
+
+// A network described by named cell and synapse types, populations of
+// cells and projections between them, independent of any `Network`. Every
+// name a population or projection refers to must already be in the model,
+// so a model is always complete.
+#[derive(Debug, Clone)]
+pub struct Model {
+  id: String,
+  cells: Vec<(String, CellType)>,
+  synapses: Vec<(String, SynapseType)>,
+  populations: Vec<Population>,
+  projections: Vec<Projection>,
+}
+
+impl Model {
+  pub fn new(id: &str) -> Model {
+    Model{
+      id: id.to_string(),
+      cells: Vec::new(),
+      synapses: Vec::new(),
+      populations: Vec::new(),
+      projections: Vec::new(),
+    }
+  }
+
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  pub fn cells(&self) -> &[(String, CellType)] {
+    &self.cells
+  }
+
+  pub fn synapses(&self) -> &[(String, SynapseType)] {
+    &self.synapses
+  }
+
+  pub fn populations(&self) -> &[Population] {
+    &self.populations
+  }
+
+  pub fn projections(&self) -> &[Projection] {
+    &self.projections
+  }
+
+  pub fn cell(&self, id: &str) -> Option<&CellType> {
+    self.cells.iter().find(|c| c.0 == id).map(|c| &c.1)
+  }
+
+  pub fn synapse(&self, id: &str) -> Option<&SynapseType> {
+    self.synapses.iter().find(|s| s.0 == id).map(|s| &s.1)
+  }
+
+  pub fn population(&self, id: &str) -> Option<&Population> {
+    self.populations.iter().find(|p| p.id == id)
+  }
+
+  pub fn projection(&self, id: &str) -> Option<&Projection> {
+    self.projections.iter().find(|p| p.id == id)
+  }
+
+  pub fn add_cell(&mut self, id: &str, cell: CellType) -> io::Result<()> {
+    if self.cell(id).is_some() || self.synapse(id).is_some() {
+      return Err(invalid_input("duplicate component id"));
+    }
+    self.cells.push((id.to_string(), cell));
+    Ok(())
+  }
+
+  pub fn add_synapse(&mut self, id: &str, synapse: SynapseType) -> io::Result<()> {
+    if self.cell(id).is_some() || self.synapse(id).is_some() {
+      return Err(invalid_input("duplicate component id"));
+    }
+    self.synapses.push((id.to_string(), synapse));
+    Ok(())
+  }
+
+  pub fn add_population(&mut self, id: &str, cell: &str, size: usize) -> io::Result<()> {
+    if self.population(id).is_some() || self.projection(id).is_some() {
+      return Err(invalid_input("duplicate population id"));
+    }
+    if self.cell(cell).is_none() {
+      return Err(invalid_input("unknown cell type"));
+    }
+    self.populations.push(Population{
+      id: id.to_string(),
+      cell: cell.to_string(),
+      size: size,
+    });
+    Ok(())
+  }
+
+  // Connections must lie within the populations and have a delay of at
+  // least one tick.
+  pub fn add_projection(&mut self, id: &str, pre: &str, post: &str, synapse: &str, connections: Vec<Connection>) -> io::Result<()> {
+    if self.population(id).is_some() || self.projection(id).is_some() {
+      return Err(invalid_input("duplicate projection id"));
+    }
+    let (pre_size, post_size) = match (self.population(pre), self.population(post)) {
+      (Some(pre), Some(post)) => (pre.size, post.size),
+      _ => return Err(invalid_input("unknown population")),
+    };
+    if self.synapse(synapse).is_none() {
+      return Err(invalid_input("unknown synapse type"));
+    }
+    for c in connections.iter() {
+      if c.pre >= pre_size || c.post >= post_size {
+        return Err(invalid_input("connection outside its populations"));
+      }
+      if !(c.delay >= 1.0) {
+        return Err(invalid_input("delay shorter than a tick"));
+      }
+    }
+    self.projections.push(Projection{
+      id: id.to_string(),
+      pre: pre.to_string(),
+      post: post.to_string(),
+      synapse: synapse.to_string(),
+      connections: connections,
+    });
+    Ok(())
+  }
+
+  // Adds a projection holding the synapses of `network` from `pre_ids` to
+  // `post_ids`, the neurons of populations `pre` and `post` in order, with
+  // their current weights and delays.
+  pub fn add_network_projection<N: Neuron, S: Synapse>(&mut self, id: &str, pre: &str, post: &str, synapse: &str, network: &Network<N, S>, pre_ids: &[usize], post_ids: &[usize]) -> io::Result<()> {
+    let connections = network.connections().iter().filter_map(|c| {
+      match (pre_ids.iter().position(|&n| n == c.pre), post_ids.iter().position(|&n| n == c.post)) {
+        (Some(pre), Some(post)) => Some(Connection{pre: pre, post: post, weight: c.weight, delay: c.delay}),
+        _ => None,
+      }
+    }).collect();
+    self.add_projection(id, pre, post, synapse, connections)
+  }
+
+  // Adds every population to `network`, with neurons from `neurons`, then
+  // every projection, with synapses from `synapses` given the synapse type
+  // and the connection in network ids. Returns the neuron ids of each
+  // population in order.
+  pub fn build<N, S, F, G>(&self, network: &mut Network<N, S>, neurons: F, synapses: G) -> Result<Vec<Vec<usize>>, NeuralError>
+    where N: Neuron, S: Synapse, F: Fn(&CellType) -> N, G: Fn(&SynapseType, &Connection) -> S {
+    let ids: Vec<Vec<usize>> = self.populations.iter().map(|population| {
+      let cell = self.cell(&population.cell).unwrap();
+      (0..population.size).map(|_| network.add_neuron(neurons(cell))).collect()
+    }).collect();
+
+    for projection in self.projections.iter() {
+      let pre_ids = &ids[self.population_index(&projection.pre)];
+      let post_ids = &ids[self.population_index(&projection.post)];
+      let synapse = self.synapse(&projection.synapse).unwrap();
+      let connections: Vec<Connection> = projection.connections.iter().map(|c| {
+        Connection{pre: pre_ids[c.pre], post: post_ids[c.post], weight: c.weight, delay: c.delay}
+      }).collect();
+      try!(network.add_connections(&connections, |c| synapses(synapse, c)));
+    }
+    Ok(ids)
+  }
+
+  fn population_index(&self, id: &str) -> usize {
+    self.populations.iter().position(|p| p.id == id).unwrap()
+  }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidInput, message)
+}
@@ -0,0 +1,244 @@
+use std::io;
+use std::io::Read;
+use std::fs::File;
+use std::path::Path;
+
+use Float;
+use connectivity::Connection;
+use izhikevich::IzhikevichConfig;
+use lif::LIFConfig;
+use stdp::STDPConfig;
+use sym::SymConfig;
+use neuroml::model::{Model, CellType, SynapseType};
+use neuroml::units::{parse_quantity, Dimension};
+use neuroml::xml::{self, Element, invalid};
+
+// Reads the subset of NeuroML2 written by `write_neuroml`: izhikevichCell,
+// iafCell and iafRefCell cells, the crate's own staticSynapse, stdpSynapse
+// and symSynapse, and the first network's populations and projections of
+// connection or connectionWD elements. Other elements are ignored, but a
+// population of an unsupported cell type is an error. Connections without
+// a weight and delay take them from their synapse type, and delays under a
+// tick are raised to one tick.
+pub fn read_neuroml<R: Read>(mut reader: R) -> io::Result<Model> {
+  let mut text = String::new();
+  try!(reader.read_to_string(&mut text));
+  let root = try!(xml::parse(&text));
+  if root.name != "neuroml" {
+    return Err(invalid("not a NeuroML document"));
+  }
+
+  let network = root.children.iter().find(|e| e.name == "network");
+  let id = network.and_then(|n| n.attribute("id")).or(root.attribute("id")).unwrap_or("");
+  let mut model = Model::new(id);
+
+  for element in root.children.iter() {
+    match &element.name[..] {
+      "izhikevichCell" => {
+        let cell = try!(read_izhikevich(element));
+        try!(data(model.add_cell(try!(required(element, "id")), cell)));
+      },
+      "iafCell" | "iafRefCell" => {
+        let cell = try!(read_iaf(element));
+        try!(data(model.add_cell(try!(required(element, "id")), cell)));
+      },
+      "staticSynapse" | "stdpSynapse" | "symSynapse" => {
+        let synapse = try!(read_synapse(element));
+        try!(data(model.add_synapse(try!(required(element, "id")), synapse)));
+      },
+      _ => {},
+    }
+  }
+
+  if let Some(network) = network {
+    for element in network.children.iter().filter(|e| e.name == "population") {
+      let cell = try!(required(element, "component"));
+      if model.cell(cell).is_none() {
+        return Err(invalid("unsupported cell type"));
+      }
+      let size = match element.attribute("size") {
+        Some(size) => try!(size.trim().parse().map_err(|_| invalid("invalid population size"))),
+        None => element.children.iter().filter(|e| e.name == "instance").count(),
+      };
+      try!(data(model.add_population(try!(required(element, "id")), cell, size)));
+    }
+
+    for element in network.children.iter().filter(|e| e.name == "projection") {
+      let pre = try!(required(element, "presynapticPopulation"));
+      let post = try!(required(element, "postsynapticPopulation"));
+      let synapse = try!(required(element, "synapse"));
+      let (weight, delay) = match model.synapse(synapse) {
+        Some(&SynapseType::Static) => (1.0, 1.0),
+        Some(&SynapseType::STDP(config)) => (config.weight, config.delay),
+        Some(&SynapseType::Sym(config)) => (config.weight, config.delay),
+        None => return Err(invalid("unknown synapse type")),
+      };
+
+      let mut connections = Vec::new();
+      for c in element.children.iter().filter(|e| e.name == "connection" || e.name == "connectionWD") {
+        let delay = try!(optional(c, "delay", Dimension::Time, delay));
+        connections.push(Connection{
+          pre: try!(cell_index(try!(required(c, "preCellId")), pre)),
+          post: try!(cell_index(try!(required(c, "postCellId")), post)),
+          weight: try!(optional(c, "weight", Dimension::None, weight)),
+          delay: if delay < 1.0 { 1.0 } else { delay },
+        });
+      }
+      try!(data(model.add_projection(try!(required(element, "id")), pre, post, synapse, connections)));
+    }
+  }
+  Ok(model)
+}
+
+pub fn open_neuroml<P: AsRef<Path>>(path: P) -> io::Result<Model> {
+  read_neuroml(try!(File::open(path)))
+}
+
+// The 2003 model. The spike peak is fixed at 30 mV, so `thresh` is not
+// read; the recovery variable starts at b * v0 unless a `u0` property says
+// otherwise.
+fn read_izhikevich(element: &Element) -> io::Result<CellType> {
+  let defaults = IzhikevichConfig::default();
+  let v = try!(quantity(element, "v0", Dimension::Voltage));
+  let b = try!(quantity(element, "b", Dimension::None));
+  Ok(CellType::Izhikevich(IzhikevichConfig{
+    v: v,
+    u: match property(element, "u0") {
+      Some(u) => try!(parse(u, Dimension::None)),
+      None => b * v,
+    },
+    a: try!(quantity(element, "a", Dimension::None)),
+    b: b,
+    c: try!(quantity(element, "c", Dimension::None)),
+    d: try!(quantity(element, "d", Dimension::None)),
+    e: try!(property_or(element, "e", Dimension::None, defaults.e)),
+    f: try!(property_or(element, "f", Dimension::None, defaults.f)),
+    is_accomodation: try!(property_flag(element, "accomodation", false)),
+  }))
+}
+
+// The membrane time constant is C / leakConductance. Threshold adaptation
+// has no NeuroML counterpart and is kept in properties.
+fn read_iaf(element: &Element) -> io::Result<CellType> {
+  let capacitance = try!(parse_quantity(try!(required(element, "C")), Dimension::Capacitance));
+  let conductance = try!(parse_quantity(try!(required(element, "leakConductance")), Dimension::Conductance));
+  if !(conductance > 0.0) {
+    return Err(invalid("invalid leak conductance"));
+  }
+  let defaults = LIFConfig::default();
+  Ok(CellType::LIF(LIFConfig{
+    v_rest: try!(quantity(element, "leakReversal", Dimension::Voltage)),
+    v_reset: try!(quantity(element, "reset", Dimension::Voltage)),
+    v_thresh: try!(quantity(element, "thresh", Dimension::Voltage)),
+    tau_m: (1e3 * capacitance / conductance) as Float,
+    refractory: try!(optional(element, "refract", Dimension::Time, 0.0)),
+    theta_plus: try!(property_or(element, "thetaPlus", Dimension::Voltage, 0.0)),
+    tau_theta: try!(property_or(element, "tauTheta", Dimension::Time, defaults.tau_theta)),
+    adaptive: try!(property_flag(element, "adaptive", false)),
+  }))
+}
+
+fn read_synapse(element: &Element) -> io::Result<SynapseType> {
+  match &element.name[..] {
+    "stdpSynapse" => {
+      let d = STDPConfig::default();
+      Ok(SynapseType::STDP(STDPConfig{
+        weight: try!(optional(element, "weight", Dimension::None, d.weight)),
+        min: try!(optional(element, "min", Dimension::None, d.min)),
+        max: try!(optional(element, "max", Dimension::None, d.max)),
+        n_pos: try!(optional(element, "nPos", Dimension::None, d.n_pos)),
+        n_neg: try!(optional(element, "nNeg", Dimension::None, d.n_neg)),
+        tau_pos: try!(optional(element, "tauPos", Dimension::Time, d.tau_pos)),
+        tau_neg: try!(optional(element, "tauNeg", Dimension::Time, d.tau_neg)),
+        a_pos: try!(optional(element, "aPos", Dimension::None, d.a_pos)),
+        a_neg: try!(optional(element, "aNeg", Dimension::None, d.a_neg)),
+        continuous: try!(flag(element.attribute("continuous"), d.continuous)),
+        scale: try!(flag(element.attribute("scale"), d.scale)),
+        delay: try!(optional(element, "delay", Dimension::Time, d.delay)),
+        dendritic: try!(optional(element, "dendritic", Dimension::None, d.dendritic)),
+      }))
+    },
+    "symSynapse" => {
+      let d = SymConfig::default();
+      Ok(SynapseType::Sym(SymConfig{
+        weight: try!(optional(element, "weight", Dimension::None, d.weight)),
+        a_sym: try!(optional(element, "aSym", Dimension::None, d.a_sym)),
+        tau_a: try!(optional(element, "tauA", Dimension::Time, d.tau_a)),
+        tau_b: try!(optional(element, "tauB", Dimension::Time, d.tau_b)),
+        delay: try!(optional(element, "delay", Dimension::Time, d.delay)),
+        dendritic: try!(optional(element, "dendritic", Dimension::None, d.dendritic)),
+        min: try!(optional(element, "min", Dimension::None, d.min)),
+        max: try!(optional(element, "max", Dimension::None, d.max)),
+      }))
+    },
+    _ => Ok(SynapseType::Static),
+  }
+}
+
+// Index of a cell in `population` from a reference "../pop[i]" or
+// "../pop/i/cell".
+fn cell_index(reference: &str, population: &str) -> io::Result<usize> {
+  let reference = reference.trim();
+  let path = if reference.starts_with("../") { &reference[3..] } else { reference };
+  let (name, index) = if path.ends_with(']') {
+    match path.find('[') {
+      Some(i) => (&path[..i], &path[i + 1..path.len() - 1]),
+      None => return Err(invalid("invalid cell reference")),
+    }
+  } else {
+    let mut parts = path.split('/');
+    match (parts.next(), parts.next()) {
+      (Some(name), Some(index)) => (name, index),
+      _ => return Err(invalid("invalid cell reference")),
+    }
+  };
+  if name != population {
+    return Err(invalid("cell reference outside its population"));
+  }
+  index.parse().map_err(|_| invalid("invalid cell reference"))
+}
+
+// Errors from building the model mean the document is inconsistent.
+fn data<T>(result: io::Result<T>) -> io::Result<T> {
+  result.map_err(|e| invalid(&e.to_string()))
+}
+
+fn required<'a>(element: &'a Element, name: &str) -> io::Result<&'a str> {
+  element.attribute(name).ok_or(invalid("missing attribute"))
+}
+
+fn parse(text: &str, dimension: Dimension) -> io::Result<Float> {
+  parse_quantity(text, dimension).map(|x| x as Float)
+}
+
+fn quantity(element: &Element, name: &str, dimension: Dimension) -> io::Result<Float> {
+  parse(try!(required(element, name)), dimension)
+}
+
+fn optional(element: &Element, name: &str, dimension: Dimension, default: Float) -> io::Result<Float> {
+  element.attribute(name).map_or(Ok(default), |text| parse(text, dimension))
+}
+
+fn flag(text: Option<&str>, default: bool) -> io::Result<bool> {
+  match text.map(|t| t.trim()) {
+    Some("true") => Ok(true),
+    Some("false") => Ok(false),
+    Some(_) => Err(invalid("invalid boolean")),
+    None => Ok(default),
+  }
+}
+
+// Value of a `<property tag=".." value=".."/>` child.
+fn property<'a>(element: &'a Element, tag: &str) -> Option<&'a str> {
+  element.children.iter()
+    .find(|e| e.name == "property" && e.attribute("tag") == Some(tag))
+    .and_then(|e| e.attribute("value"))
+}
+
+fn property_or(element: &Element, tag: &str, dimension: Dimension, default: Float) -> io::Result<Float> {
+  property(element, tag).map_or(Ok(default), |text| parse(text, dimension))
+}
+
+fn property_flag(element: &Element, tag: &str, default: bool) -> io::Result<bool> {
+  flag(property(element, tag), default)
+}
@@ -0,0 +1,42 @@
+use std::io;
+
+use neuroml::xml::invalid;
+
+// NeuroML quantities are a number followed by a unit, e.g. "-65mV". They
+// are converted to the units the crate works in: mV, ms (one tick), nF
+// and nS.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dimension {
+  None,
+  Voltage,
+  Time,
+  Capacitance,
+  Conductance,
+}
+
+pub fn parse_quantity(text: &str, dimension: Dimension) -> io::Result<f64> {
+  let text = text.trim();
+  let split = text.find(|c: char| c.is_alphabetic() && c != 'e' && c != 'E').unwrap_or(text.len());
+  let (number, unit) = (text[..split].trim(), text[split..].trim());
+  let value: f64 = try!(number.parse().map_err(|_| invalid("invalid number")));
+
+  let scale = match (dimension, unit) {
+    (Dimension::None, "") => 1.0,
+    (Dimension::Voltage, "V") => 1e3,
+    (Dimension::Voltage, "mV") => 1.0,
+    (Dimension::Time, "s") => 1e3,
+    (Dimension::Time, "ms") => 1.0,
+    (Dimension::Capacitance, "F") => 1e9,
+    (Dimension::Capacitance, "uF") => 1e3,
+    (Dimension::Capacitance, "nF") => 1.0,
+    (Dimension::Capacitance, "pF") => 1e-3,
+    (Dimension::Conductance, "S") => 1e9,
+    (Dimension::Conductance, "mS") => 1e6,
+    (Dimension::Conductance, "uS") => 1e3,
+    (Dimension::Conductance, "nS") => 1.0,
+    (Dimension::Conductance, "pS") => 1e-3,
+    _ => return Err(invalid("unsupported unit")),
+  };
+  Ok(value * scale)
+}
@@ -0,0 +1,161 @@
+use std::io;
+use std::io::Write;
+
+use Float;
+use izhikevich::IzhikevichConfig;
+use lif::LIFConfig;
+use neuroml::model::{Model, CellType, SynapseType};
+use neuroml::xml::Element;
+
+const NAMESPACE: &'static str = "http://www.neuroml.org/schema/neuroml2";
+
+// Writes `model` as a NeuroML2 document, taking one tick as 1 ms.
+// Izhikevich cells become izhikevichCell and LIF cells iafRefCell with a
+// 1 nF capacitance; parameters NeuroML has no place for are written as
+// properties. The synapse types have no NeuroML2 counterpart and are
+// written as staticSynapse, stdpSynapse and symSynapse elements, and every
+// connection as a connectionWD with its weight and delay.
+pub fn write_neuroml<W: Write>(mut writer: W, model: &Model) -> io::Result<()> {
+  let mut root = Element::new("neuroml");
+  root.set("xmlns", NAMESPACE);
+  root.set("id", model.id());
+
+  for &(ref id, ref cell) in model.cells().iter() {
+    root.push(match *cell {
+      CellType::Izhikevich(ref config) => izhikevich(id, config),
+      CellType::LIF(ref config) => iaf(id, config),
+    });
+  }
+
+  for &(ref id, ref synapse) in model.synapses().iter() {
+    let element = match *synapse {
+      SynapseType::Static => {
+        let mut element = Element::new("staticSynapse");
+        element.set("id", id);
+        element
+      },
+      SynapseType::STDP(ref config) => {
+        let mut element = Element::new("stdpSynapse");
+        element.set("id", id);
+        element.set("weight", config.weight);
+        element.set("min", config.min);
+        element.set("max", config.max);
+        element.set("nPos", config.n_pos);
+        element.set("nNeg", config.n_neg);
+        element.set("tauPos", ms(config.tau_pos));
+        element.set("tauNeg", ms(config.tau_neg));
+        element.set("aPos", config.a_pos);
+        element.set("aNeg", config.a_neg);
+        element.set("continuous", config.continuous);
+        element.set("scale", config.scale);
+        element.set("delay", ms(config.delay));
+        element.set("dendritic", config.dendritic);
+        element
+      },
+      SynapseType::Sym(ref config) => {
+        let mut element = Element::new("symSynapse");
+        element.set("id", id);
+        element.set("weight", config.weight);
+        element.set("min", config.min);
+        element.set("max", config.max);
+        element.set("aSym", config.a_sym);
+        element.set("tauA", ms(config.tau_a));
+        element.set("tauB", ms(config.tau_b));
+        element.set("delay", ms(config.delay));
+        element.set("dendritic", config.dendritic);
+        element
+      },
+    };
+    root.push(element);
+  }
+
+  let mut network = Element::new("network");
+  network.set("id", model.id());
+  for population in model.populations().iter() {
+    let mut element = Element::new("population");
+    element.set("id", &population.id);
+    element.set("component", &population.cell);
+    element.set("size", population.size);
+    network.push(element);
+  }
+
+  for projection in model.projections().iter() {
+    let mut element = Element::new("projection");
+    element.set("id", &projection.id);
+    element.set("presynapticPopulation", &projection.pre);
+    element.set("postsynapticPopulation", &projection.post);
+    element.set("synapse", &projection.synapse);
+    for (k, c) in projection.connections.iter().enumerate() {
+      let mut connection = Element::new("connectionWD");
+      connection.set("id", k);
+      connection.set("preCellId", format!("../{}[{}]", projection.pre, c.pre));
+      connection.set("postCellId", format!("../{}[{}]", projection.post, c.post));
+      connection.set("weight", c.weight);
+      connection.set("delay", ms(c.delay));
+      element.push(connection);
+    }
+    network.push(element);
+  }
+  root.push(network);
+
+  try!(writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+  root.write(&mut writer, 0)
+}
+
+// Only what differs from the model's defaults is written as properties.
+fn izhikevich(id: &str, config: &IzhikevichConfig) -> Element {
+  let defaults = IzhikevichConfig::default();
+  let mut element = Element::new("izhikevichCell");
+  element.set("id", id);
+  element.set("v0", mv(config.v));
+  element.set("thresh", "30mV");
+  element.set("a", config.a);
+  element.set("b", config.b);
+  element.set("c", config.c);
+  element.set("d", config.d);
+  if config.u != config.b * config.v {
+    element.push(property("u0", config.u));
+  }
+  if config.e != defaults.e {
+    element.push(property("e", config.e));
+  }
+  if config.f != defaults.f {
+    element.push(property("f", config.f));
+  }
+  if config.is_accomodation {
+    element.push(property("accomodation", true));
+  }
+  element
+}
+
+fn iaf(id: &str, config: &LIFConfig) -> Element {
+  let mut element = Element::new("iafRefCell");
+  element.set("id", id);
+  element.set("C", "1nF");
+  element.set("leakConductance", format!("{}nS", 1e3 / config.tau_m as f64));
+  element.set("leakReversal", mv(config.v_rest));
+  element.set("thresh", mv(config.v_thresh));
+  element.set("reset", mv(config.v_reset));
+  element.set("refract", ms(config.refractory));
+  if config.adaptive || config.theta_plus != 0.0 {
+    element.push(property("thetaPlus", mv(config.theta_plus)));
+    element.push(property("tauTheta", ms(config.tau_theta)));
+    element.push(property("adaptive", config.adaptive));
+  }
+  element
+}
+
+fn property<T: ToString>(tag: &str, value: T) -> Element {
+  let mut element = Element::new("property");
+  element.set("tag", tag);
+  element.set("value", value);
+  element
+}
+
+fn mv(v: Float) -> String {
+  format!("{}mV", v)
+}
+
+fn ms(t: Float) -> String {
+  format!("{}ms", t)
+}
@@ -0,0 +1,230 @@
+use std::io;
+use std::io::Write;
+
+// Just enough XML for NeuroML: elements and attributes. Text, comments,
+// processing instructions, CDATA and doctypes are skipped, and namespace
+// prefixes are kept as part of the name.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+  pub name: String,
+  pub attributes: Vec<(String, String)>,
+  pub children: Vec<Element>,
+}
+
+impl Element {
+  pub fn new(name: &str) -> Element {
+    Element{
+      name: name.to_string(),
+      attributes: Vec::new(),
+      children: Vec::new(),
+    }
+  }
+
+  pub fn attribute(&self, name: &str) -> Option<&str> {
+    self.attributes.iter().find(|a| a.0 == name).map(|a| &a.1[..])
+  }
+
+  pub fn set<T: ToString>(&mut self, name: &str, value: T) {
+    self.attributes.push((name.to_string(), value.to_string()));
+  }
+
+  pub fn push(&mut self, child: Element) {
+    self.children.push(child);
+  }
+
+  pub fn write<W: Write>(&self, writer: &mut W, depth: usize) -> io::Result<()> {
+    let indent: String = (0..depth).map(|_| "  ").collect();
+    try!(write!(writer, "{}<{}", indent, self.name));
+    for &(ref name, ref value) in self.attributes.iter() {
+      try!(write!(writer, " {}=\"{}\"", name, escape(value)));
+    }
+    if self.children.is_empty() {
+      return writeln!(writer, "/>");
+    }
+
+    try!(writeln!(writer, ">"));
+    for child in self.children.iter() {
+      try!(child.write(writer, depth + 1));
+    }
+    writeln!(writer, "{}</{}>", indent, self.name)
+  }
+}
+
+// The root element of a document.
+pub fn parse(text: &str) -> io::Result<Element> {
+  let mut parser = Parser{text: text, pos: 0};
+  try!(parser.skip_misc());
+  if parser.rest().is_empty() {
+    return Err(invalid("missing root element"));
+  }
+  let root = try!(parser.element());
+  try!(parser.skip_misc());
+  if !parser.rest().is_empty() {
+    return Err(invalid("content after the root element"));
+  }
+  Ok(root)
+}
+
+pub fn invalid(message: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn escape(value: &str) -> String {
+  value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn unescape(value: &str) -> io::Result<String> {
+  let mut result = String::with_capacity(value.len());
+  let mut rest = value;
+  while let Some(start) = rest.find('&') {
+    result.push_str(&rest[..start]);
+    let end = match rest[start..].find(';') {
+      Some(end) => start + end,
+      None => return Err(invalid("unterminated entity")),
+    };
+    let entity = &rest[start + 1..end];
+    let c = match entity {
+      "lt" => '<',
+      "gt" => '>',
+      "amp" => '&',
+      "quot" => '"',
+      "apos" => '\'',
+      _ if entity.starts_with("#x") => try!(character(&entity[2..], 16)),
+      _ if entity.starts_with('#') => try!(character(&entity[1..], 10)),
+      _ => return Err(invalid("unknown entity")),
+    };
+    result.push(c);
+    rest = &rest[end + 1..];
+  }
+  result.push_str(rest);
+  Ok(result)
+}
+
+fn character(digits: &str, radix: u32) -> io::Result<char> {
+  u32::from_str_radix(digits, radix).ok()
+    .and_then(::std::char::from_u32)
+    .ok_or(invalid("invalid character reference"))
+}
+
+struct Parser<'a> {
+  text: &'a str,
+  pos: usize,
+}
+
+impl<'a> Parser<'a> {
+  fn rest(&self) -> &'a str {
+    &self.text[self.pos..]
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+    self.pos += rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+  }
+
+  // Skips past `end`, which must occur.
+  fn skip_past(&mut self, end: &str) -> io::Result<()> {
+    match self.rest().find(end) {
+      Some(i) => {
+        self.pos += i + end.len();
+        Ok(())
+      },
+      None => Err(invalid("unexpected end of document")),
+    }
+  }
+
+  // Skips whitespace, comments, processing instructions and doctypes.
+  fn skip_misc(&mut self) -> io::Result<()> {
+    loop {
+      self.skip_whitespace();
+      let rest = self.rest();
+      if rest.starts_with("<?") {
+        try!(self.skip_past("?>"));
+      } else if rest.starts_with("<!--") {
+        try!(self.skip_past("-->"));
+      } else if rest.starts_with("<!") {
+        try!(self.skip_past(">"));
+      } else {
+        return Ok(());
+      }
+    }
+  }
+
+  fn name(&mut self) -> io::Result<String> {
+    let rest = self.rest();
+    let len = rest.find(|c: char| c.is_whitespace() || c == '/' || c == '>' || c == '=').unwrap_or(rest.len());
+    if len == 0 {
+      return Err(invalid("expected a name"));
+    }
+    self.pos += len;
+    Ok(rest[..len].to_string())
+  }
+
+  fn expect(&mut self, token: &str) -> io::Result<()> {
+    if !self.rest().starts_with(token) {
+      return Err(invalid("unexpected character"));
+    }
+    self.pos += token.len();
+    Ok(())
+  }
+
+  fn element(&mut self) -> io::Result<Element> {
+    try!(self.expect("<"));
+    let mut element = Element::new(&try!(self.name()));
+
+    loop {
+      self.skip_whitespace();
+      if self.rest().starts_with("/>") {
+        self.pos += 2;
+        return Ok(element);
+      }
+      if self.rest().starts_with(">") {
+        self.pos += 1;
+        break;
+      }
+
+      let name = try!(self.name());
+      self.skip_whitespace();
+      try!(self.expect("="));
+      self.skip_whitespace();
+      let quote = match self.rest().chars().next() {
+        Some(c) if c == '"' || c == '\'' => c,
+        _ => return Err(invalid("expected a quoted attribute value")),
+      };
+      self.pos += 1;
+      let len = match self.rest().find(quote) {
+        Some(len) => len,
+        None => return Err(invalid("unterminated attribute value")),
+      };
+      let value = try!(unescape(&self.rest()[..len]));
+      self.pos += len + 1;
+      element.attributes.push((name, value));
+    }
+
+    loop {
+      match self.rest().find('<') {
+        Some(i) => self.pos += i,
+        None => return Err(invalid("unexpected end of document")),
+      }
+      let rest = self.rest();
+      if rest.starts_with("</") {
+        self.pos += 2;
+        if try!(self.name()) != element.name {
+          return Err(invalid("mismatched closing tag"));
+        }
+        self.skip_whitespace();
+        try!(self.expect(">"));
+        return Ok(element);
+      } else if rest.starts_with("<!--") {
+        try!(self.skip_past("-->"));
+      } else if rest.starts_with("<![CDATA[") {
+        try!(self.skip_past("]]>"));
+      } else if rest.starts_with("<?") {
+        try!(self.skip_past("?>"));
+      } else {
+        let child = try!(self.element());
+        element.children.push(child);
+      }
+    }
+  }
+}
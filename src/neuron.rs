@@ -1,4 +1,5 @@
 use Float;
+use receptor::Receptors;
 
 // Neuron represents an excitable element in a network. It decides which signals to pass on.
 pub trait Neuron {
@@ -6,4 +7,27 @@ pub trait Neuron {
   fn tick(&mut self, tau: Float);
   fn threshold(&mut self) -> Float;
   fn reset(&mut self);
+
+  // Membrane potential, read by voltage-dependent synapses every tick.
+  fn voltage(&self) -> Float;
+
+  // Receive a spike arriving `offset` (in [0, 1)) into the next tick.
+  // Neurons that cannot resolve sub-tick timing treat it as `recv`.
+  fn recv_at(&mut self, v: Float, _offset: Float) -> Float {
+    self.recv(v)
+  }
+
+  // Receive a spike aimed at specific postsynaptic receptors. Neurons
+  // without receptor channels treat it as `recv_at`.
+  fn recv_receptors(&mut self, v: Float, offset: Float, _receptors: Receptors) -> Float {
+    self.recv_at(v, offset)
+  }
+
+  // Receive a conductance `g` with reversal potential `e_rev`. The
+  // resulting current is g(e_rev - v); neurons that cannot track the
+  // driving force within a tick take it from the current voltage.
+  fn recv_conductance(&mut self, g: Float, e_rev: Float) -> Float {
+    let v = self.voltage();
+    self.recv(g * (e_rev - v))
+  }
 }
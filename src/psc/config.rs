@@ -0,0 +1,71 @@
+use Float;
+use std::default::Default;
+
+// Time course of the postsynaptic current evoked by a single spike. Every
+// kernel is normalized so its peak equals the synaptic weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub enum Kernel {
+  // w * exp(-t / tau)
+  Exponential,
+
+  // w * norm * (exp(-t / tau) - exp(-t / tau_rise)), tau_rise < tau. Falls
+  // back to the exponential kernel when tau_rise is 0 and to the alpha
+  // kernel when tau_rise equals tau.
+  DualExponential,
+
+  // w * (t / tau) * exp(1 - t / tau), peaks at t = tau
+  Alpha,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct PSCConfig {
+  pub kernel: Kernel,
+
+  // decay time constants of the excitatory and inhibitory currents
+  pub tau_exc: Float,
+  pub tau_inh: Float,
+
+  // rise time constants, only used by the dual-exponential kernel
+  pub tau_rise_exc: Float,
+  pub tau_rise_inh: Float,
+}
+
+impl Default for PSCConfig {
+  fn default() -> PSCConfig {
+    PSCConfig::exponential()
+  }
+}
+
+impl PSCConfig {
+  pub fn exponential() -> PSCConfig {
+    PSCConfig{
+      kernel: Kernel::Exponential,
+      tau_exc: 5.0,
+      tau_inh: 10.0,
+      tau_rise_exc: 0.0,
+      tau_rise_inh: 0.0,
+    }
+  }
+
+  pub fn dual_exponential() -> PSCConfig {
+    PSCConfig{
+      kernel: Kernel::DualExponential,
+      tau_exc: 5.0,
+      tau_inh: 10.0,
+      tau_rise_exc: 0.5,
+      tau_rise_inh: 1.0,
+    }
+  }
+
+  pub fn alpha() -> PSCConfig {
+    PSCConfig{
+      kernel: Kernel::Alpha,
+      tau_exc: 2.0,
+      tau_inh: 5.0,
+      tau_rise_exc: 0.0,
+      tau_rise_inh: 0.0,
+    }
+  }
+}
@@ -0,0 +1,5 @@
+pub use self::config::{PSCConfig, Kernel};
+pub use self::neuron::PSCNeuron;
+
+pub mod config;
+pub mod neuron;
@@ -0,0 +1,172 @@
+use Float;
+use std::f32::consts::E;
+
+use neuron::Neuron;
+use psc::config::{PSCConfig, Kernel};
+
+// Summed postsynaptic current of one sign. The kernels are linear so all
+// synapses onto a neuron share a single set of state variables, and decay
+// costs the same no matter how many synapses converge on it.
+#[derive(Debug, Clone, Copy)]
+struct Channel {
+  kernel: Kernel,
+
+  tau: Float,
+  tau_rise: Float,
+
+  // peak normalization of the dual-exponential kernel
+  norm: Float,
+
+  // decaying and rising components; the alpha kernel uses `rise` for its
+  // driving exponential
+  decay: Float,
+  rise: Float,
+
+  // input arriving at the start of the next tick
+  next: Float,
+}
+
+impl Channel {
+  fn new(kernel: Kernel, tau: Float, tau_rise: Float) -> Channel {
+    // The dual exponential's normalization divides by zero without a rise
+    // time or with tau_rise = tau, where it reduces to the exponential and
+    // alpha kernels.
+    let kernel = match kernel {
+      Kernel::DualExponential if tau_rise <= 0.0 => Kernel::Exponential,
+      Kernel::DualExponential if (tau - tau_rise).abs() < 1e-4 * tau => Kernel::Alpha,
+      kernel => kernel,
+    };
+
+    let norm = if kernel == Kernel::DualExponential {
+      let peak = tau * tau_rise / (tau - tau_rise) * (tau / tau_rise).ln();
+      1.0 / ((-peak / tau).exp() - (-peak / tau_rise).exp())
+    } else {
+      1.0
+    };
+
+    Channel{
+      kernel: kernel,
+      tau: tau,
+      tau_rise: tau_rise,
+      norm: norm,
+      decay: 0.0,
+      rise: 0.0,
+      next: 0.0,
+    }
+  }
+
+  fn input(&mut self, w: Float) {
+    match self.kernel {
+      Kernel::Exponential => {
+        self.decay += w;
+      },
+      Kernel::DualExponential => {
+        self.decay += w * self.norm;
+        self.rise += w * self.norm;
+      },
+      Kernel::Alpha => {
+        self.rise += w;
+      },
+    }
+  }
+
+  fn current(&self) -> Float {
+    match self.kernel {
+      Kernel::Exponential | Kernel::Alpha => self.decay,
+      Kernel::DualExponential => self.decay - self.rise,
+    }
+  }
+
+  fn step(&mut self, dt: Float) {
+    let p = (-dt / self.tau).exp();
+    match self.kernel {
+      Kernel::Exponential => {
+        self.decay *= p;
+      },
+      Kernel::DualExponential => {
+        self.decay *= p;
+        self.rise *= (-dt / self.tau_rise).exp();
+      },
+      Kernel::Alpha => {
+        // exact solution of tau * y' = -y + e * z, tau * z' = -z
+        self.decay = (self.decay + E * dt / self.tau * self.rise) * p;
+        self.rise *= p;
+      },
+    }
+
+    let next = self.next;
+    self.next = 0.0;
+    self.input(next);
+  }
+}
+
+// Wraps a neuron with current-based synapses. Spikes delivered through
+// `recv_at` are filtered by the configured kernel, positive weights into
+// the excitatory channel and negative weights into the inhibitory one.
+// Direct input through `recv` still reaches the wrapped neuron unfiltered.
+#[derive(Debug, Clone, Copy)]
+pub struct PSCNeuron<N: Neuron> {
+  neuron: N,
+
+  exc: Channel,
+  inh: Channel,
+}
+
+impl<N: Neuron> PSCNeuron<N> {
+  pub fn new(config: PSCConfig, neuron: N) -> PSCNeuron<N> {
+    PSCNeuron{
+      neuron: neuron,
+      exc: Channel::new(config.kernel, config.tau_exc, config.tau_rise_exc),
+      inh: Channel::new(config.kernel, config.tau_inh, config.tau_rise_inh),
+    }
+  }
+
+  pub fn neuron(&self) -> &N {
+    &self.neuron
+  }
+
+  // Synaptic current delivered to the wrapped neuron on the next tick.
+  pub fn current(&self) -> Float {
+    self.exc.current() + self.inh.current()
+  }
+}
+
+impl<N: Neuron> Neuron for PSCNeuron<N> {
+  fn recv(&mut self, v: Float) -> Float {
+    self.neuron.recv(v)
+  }
+
+  fn recv_at(&mut self, v: Float, offset: Float) -> Float {
+    // Split the spike between this tick and the next, as the point
+    // neurons do with their input charge.
+    let channel = if v < 0.0 { &mut self.inh } else { &mut self.exc };
+    channel.input(v * (1.0 - offset));
+    channel.next += v * offset;
+    channel.current()
+  }
+
+  fn recv_conductance(&mut self, g: Float, e_rev: Float) -> Float {
+    self.neuron.recv_conductance(g, e_rev)
+  }
+
+  fn tick(&mut self, tau: Float) {
+    let i = self.current();
+    self.neuron.recv(i);
+    self.neuron.tick(tau);
+
+    self.exc.step(tau);
+    self.inh.step(tau);
+  }
+
+  fn threshold(&mut self) -> Float {
+    self.neuron.threshold()
+  }
+
+  fn reset(&mut self) {
+    self.neuron.reset()
+  }
+
+  fn voltage(&self) -> Float {
+    self.neuron.voltage()
+  }
+}
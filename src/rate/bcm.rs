@@ -0,0 +1,146 @@
+use Float;
+use synapse::Synapse;
+use trace::Trace;
+use rate::config::RateConfig;
+use rate::integral::{exp_integral, moment_integral};
+
+// Bienenstock, Cooper & Munro (1982): dw/dt = eta * x * y * (y - theta).
+// The threshold slides towards y^2 / target_rate, so sustained high
+// postsynaptic activity makes potentiation harder.
+// The weight and threshold are integrated in closed form between spikes
+// like `HebbianSynapse`.
+#[derive(Debug, Clone, Copy)]
+pub struct BCMSynapse<T: Trace> {
+  weight: Float,
+
+  min: Float,
+  max: Float,
+
+  eta: Float,
+  tau_rate: Float,
+
+  pre_rate: T,
+  post_rate: T,
+
+  // sliding modification threshold
+  theta: Float,
+  tau_theta: Float,
+  target_rate: Float,
+
+  last_time: Float,
+
+  delay: Float,
+  dendritic: Float,
+}
+
+impl<T: Trace> BCMSynapse<T> {
+  pub fn new(config: RateConfig) -> BCMSynapse<T> {
+    return BCMSynapse{
+      weight: config.weight,
+      min: config.min,
+      max: config.max,
+      eta: config.eta,
+      tau_rate: config.tau_rate,
+      pre_rate: T::new(config.tau_rate, true),
+      post_rate: T::new(config.tau_rate, true),
+      theta: config.target_rate,
+      tau_theta: config.tau_theta,
+      target_rate: config.target_rate,
+      last_time: 0.0,
+      delay: config.delay,
+      dendritic: config.dendritic,
+    }
+  }
+
+  pub fn theta(&self) -> Float {
+    self.theta
+  }
+
+  fn integrate(&mut self, delta: Float) {
+    self.weight = self.weight + delta;
+    if self.weight > self.max {
+      self.weight = self.max;
+    } else if self.weight < self.min {
+      self.weight = self.min;
+    }
+  }
+
+  // Integrates the rule up to `now` with x and y decaying from their
+  // values at the last spike, when the traces were last read. Computed
+  // in f64 as the terms nearly cancel for short intervals.
+  fn settle(&mut self, now: Float) -> Float { // delta
+    let dt = (now - self.last_time) as f64;
+    if dt <= 0.0 {
+      return 0.0;
+    }
+
+    let a = 1.0 / self.tau_rate as f64;
+    let x = self.pre_rate.read(self.last_time) as f64;
+    let y = self.post_rate.read(self.last_time) as f64;
+    let eta = self.eta as f64;
+    let w = self.weight as f64;
+    self.last_time = now;
+
+    // theta(s) = theta * e^(-bs) + c * g(s) follows y(s)^2 / target_rate
+    // with g(s) = (e^(-2as) - e^(-bs)) / (b - 2a), or s e^(-2as) when
+    // b = 2a
+    let theta = self.theta as f64;
+    let b = 1.0 / self.tau_theta as f64;
+    let c = y * y * b / self.target_rate as f64;
+    let k = b - 2.0 * a;
+
+    let (g, integral_g) = if k.abs() * dt < 1e-6 {
+      (dt * (-2.0 * a * dt).exp(), moment_integral(4.0 * a, dt))
+    } else {
+      (((-2.0 * a * dt).exp() - (-b * dt).exp()) / k,
+        (exp_integral(4.0 * a, dt) - exp_integral(2.0 * a + b, dt)) / k)
+    };
+
+    let xyy = x * y * y * exp_integral(3.0 * a, dt);
+    let xy_theta = x * y * (theta * exp_integral(2.0 * a + b, dt) + c * integral_g);
+    self.theta = (theta * (-b * dt).exp() + c * g) as Float;
+    let weight = w + eta * (xyy - xy_theta);
+
+    let delta = weight as Float - self.weight;
+    self.integrate(delta);
+    delta
+  }
+}
+
+impl<T: Trace> Synapse for BCMSynapse<T> {
+  fn weight(&self) -> Float {
+    self.weight
+  }
+
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
+    self.delay
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float { // delta
+    let delta = self.settle(now);
+    self.pre_rate.update(1.0 / self.tau_rate, now);
+    delta
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float { // delta
+    let delta = self.settle(now);
+    self.post_rate.update(1.0 / self.tau_rate, now);
+    delta
+  }
+}
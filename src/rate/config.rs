@@ -0,0 +1,46 @@
+use Float;
+use std::default::Default;
+
+// Rate-based learning rules. Pre and postsynaptic rates are estimated in
+// spikes per tick by exponentially decaying traces that jump by
+// 1 / tau_rate at every spike.
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct RateConfig {
+  pub weight: Float,
+  pub min: Float,
+  pub max: Float,
+
+  // learning rate
+  pub eta: Float,
+
+  // time constant of the rate estimates
+  pub tau_rate: Float,
+
+  // BCM only: time constant of the sliding threshold and the rate whose
+  // square it is normalized by
+  pub tau_theta: Float,
+  pub target_rate: Float,
+
+  pub delay: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float,
+}
+
+impl Default for RateConfig {
+  fn default() -> RateConfig {
+    RateConfig{
+      weight: 1.0,
+      min: 0.0,
+      max: 10.0,
+      eta: 1.0,
+      tau_rate: 100.0,
+      tau_theta: 1000.0,
+      target_rate: 0.01,
+      delay: 1.0,
+      dendritic: 0.0,
+    }
+  }
+}
@@ -0,0 +1,116 @@
+use Float;
+use synapse::Synapse;
+use trace::Trace;
+use rate::config::RateConfig;
+use rate::integral::exp_integral;
+
+// dw/dt = eta * x * y for presynaptic rate x and postsynaptic rate y.
+// Between spikes both rates decay exponentially with tau_rate, so the
+// weight is integrated in closed form at every pre or post spike and does
+// not depend on how the spikes are spaced. The closed form assumes the
+// decay of `ExpTrace`.
+#[derive(Debug, Clone, Copy)]
+pub struct HebbianSynapse<T: Trace> {
+  weight: Float,
+
+  min: Float,
+  max: Float,
+
+  eta: Float,
+  tau_rate: Float,
+
+  pre_rate: T,
+  post_rate: T,
+
+  last_time: Float,
+
+  delay: Float,
+  dendritic: Float,
+}
+
+impl<T: Trace> HebbianSynapse<T> {
+  pub fn new(config: RateConfig) -> HebbianSynapse<T> {
+    return HebbianSynapse{
+      weight: config.weight,
+      min: config.min,
+      max: config.max,
+      eta: config.eta,
+      tau_rate: config.tau_rate,
+      pre_rate: T::new(config.tau_rate, true),
+      post_rate: T::new(config.tau_rate, true),
+      last_time: 0.0,
+      delay: config.delay,
+      dendritic: config.dendritic,
+    }
+  }
+
+  fn integrate(&mut self, delta: Float) {
+    self.weight = self.weight + delta;
+    if self.weight > self.max {
+      self.weight = self.max;
+    } else if self.weight < self.min {
+      self.weight = self.min;
+    }
+  }
+
+  // Integrates the rule up to `now` with x and y decaying from their
+  // values at the last spike, when the traces were last read. Computed
+  // in f64 as the terms nearly cancel for short intervals.
+  fn settle(&mut self, now: Float) -> Float { // delta
+    let dt = (now - self.last_time) as f64;
+    if dt <= 0.0 {
+      return 0.0;
+    }
+
+    let a = 1.0 / self.tau_rate as f64;
+    let x = self.pre_rate.read(self.last_time) as f64;
+    let y = self.post_rate.read(self.last_time) as f64;
+    let eta = self.eta as f64;
+    let w = self.weight as f64;
+    self.last_time = now;
+
+    let weight = w + eta * x * y * exp_integral(2.0 * a, dt);
+
+    let delta = weight as Float - self.weight;
+    self.integrate(delta);
+    delta
+  }
+}
+
+impl<T: Trace> Synapse for HebbianSynapse<T> {
+  fn weight(&self) -> Float {
+    self.weight
+  }
+
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
+    self.delay
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float { // delta
+    let delta = self.settle(now);
+    self.pre_rate.update(1.0 / self.tau_rate, now);
+    delta
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float { // delta
+    let delta = self.settle(now);
+    self.post_rate.update(1.0 / self.tau_rate, now);
+    delta
+  }
+}
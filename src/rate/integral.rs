@@ -0,0 +1,11 @@
+// Integrals of the decaying rates between spikes, shared by the rules.
+
+// The integral of e^(-as) over [0, dt].
+pub fn exp_integral(a: f64, dt: f64) -> f64 {
+  (1.0 - (-a * dt).exp()) / a
+}
+
+// The integral of s e^(-as) over [0, dt].
+pub fn moment_integral(a: f64, dt: f64) -> f64 {
+  (1.0 - (-a * dt).exp() * (1.0 + a * dt)) / (a * a)
+}
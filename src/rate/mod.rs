@@ -0,0 +1,11 @@
+pub use self::config::RateConfig;
+pub use self::hebbian::HebbianSynapse;
+pub use self::oja::OjaSynapse;
+pub use self::bcm::BCMSynapse;
+
+pub mod config;
+pub mod hebbian;
+pub mod oja;
+pub mod bcm;
+
+mod integral;
@@ -0,0 +1,66 @@
+use Float;
+use std::mem;
+use readout::config::ReadoutConfig;
+
+// Low-pass filters the spike trains of a set of neurons and records
+// snapshots of the filtered values as state vectors for a `Readout`.
+pub struct StateCollector {
+  neuron_ids: Vec<usize>,
+
+  decay: Float,
+
+  // filtered spike train of each neuron, in the order of `neuron_ids`
+  state: Vec<Float>,
+
+  states: Vec<Vec<Float>>,
+}
+
+impl StateCollector {
+  pub fn new(config: ReadoutConfig, neuron_ids: &[usize]) -> StateCollector {
+    StateCollector{
+      neuron_ids: neuron_ids.to_vec(),
+      decay: (-1.0 / config.tau).exp(),
+      state: vec![0.0; neuron_ids.len()],
+      states: Vec::new(),
+    }
+  }
+
+  pub fn neuron_ids(&self) -> &[usize] {
+    &self.neuron_ids
+  }
+
+  // Advance the filters by one tick given the `outputs` of a single tick
+  // of the `Network`. Every positive output counts as one spike.
+  pub fn observe(&mut self, outputs: &[Float]) {
+    for (x, &neuron_id) in self.state.iter_mut().zip(self.neuron_ids.iter()) {
+      *x *= self.decay;
+      if outputs[neuron_id] > 0.0 {
+        *x += 1.0;
+      }
+    }
+  }
+
+  pub fn state(&self) -> &[Float] {
+    &self.state
+  }
+
+  // Store a snapshot of the current state.
+  pub fn record(&mut self) {
+    self.states.push(self.state.clone());
+  }
+
+  pub fn states(&self) -> &[Vec<Float>] {
+    &self.states
+  }
+
+  pub fn take_states(&mut self) -> Vec<Vec<Float>> {
+    mem::replace(&mut self.states, Vec::new())
+  }
+
+  // Clear the filters, e.g. between independent trials.
+  pub fn reset(&mut self) {
+    for x in self.state.iter_mut() {
+      *x = 0.0;
+    }
+  }
+}
@@ -0,0 +1,25 @@
+use Float;
+use std::default::Default;
+
+// Linear readouts of a spiking reservoir, as in the liquid state machine
+// of Maass et al. (2002). Times are in ticks.
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ReadoutConfig {
+  // time constant of the exponential filter applied to each spike train
+  pub tau: Float,
+
+  // ridge (Tikhonov) penalty on the readout weights, the bias is not
+  // penalised
+  pub ridge: Float,
+}
+
+impl Default for ReadoutConfig {
+  fn default() -> ReadoutConfig {
+    ReadoutConfig{
+      tau: 20.0,
+      ridge: 1e-2,
+    }
+  }
+}
@@ -0,0 +1,7 @@
+pub use self::config::ReadoutConfig;
+pub use self::collector::StateCollector;
+pub use self::readout::Readout;
+
+pub mod config;
+pub mod collector;
+pub mod readout;
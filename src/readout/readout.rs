@@ -0,0 +1,182 @@
+use Float;
+use readout::config::ReadoutConfig;
+
+// A linear map from state vectors to outputs, fit by ridge regression.
+pub struct Readout {
+  pub inputs: usize,
+  pub outputs: usize,
+
+  // row-major `outputs` x (`inputs` + 1), the last column is the bias
+  pub weights: Vec<Float>,
+}
+
+impl Readout {
+  // Solves (X'X + ridge * I) W = X'Y by Cholesky decomposition, with X
+  // the states extended by a constant column and Y the targets. The
+  // normal equations are accumulated in double precision. Returns `None`
+  // when there are no samples, when states and targets do not pair up
+  // into rows of equal length, or when the system is singular, which a
+  // positive `ridge` rules out.
+  pub fn fit(config: ReadoutConfig, states: &[Vec<Float>], targets: &[Vec<Float>]) -> Option<Readout> {
+    if states.is_empty() || states.len() != targets.len() {
+      return None;
+    }
+
+    let inputs = states[0].len();
+    let outputs = targets[0].len();
+    if states.iter().any(|s| s.len() != inputs) || targets.iter().any(|t| t.len() != outputs) {
+      return None;
+    }
+    let n = inputs + 1;
+
+    let mut a = vec![0.0f64; n * n];
+    let mut b = vec![0.0f64; n * outputs];
+    let mut x = vec![0.0f64; n];
+    for (state, target) in states.iter().zip(targets.iter()) {
+      for i in 0..inputs {
+        x[i] = state[i] as f64;
+      }
+      x[inputs] = 1.0;
+
+      for i in 0..n {
+        if x[i] == 0.0 {
+          continue;
+        }
+        for j in 0..(i + 1) {
+          a[i * n + j] += x[i] * x[j];
+        }
+        for k in 0..outputs {
+          b[i * outputs + k] += x[i] * target[k] as f64;
+        }
+      }
+    }
+    for i in 0..inputs {
+      a[i * n + i] += config.ridge as f64;
+    }
+
+    // lower triangle of `a` becomes L with A = LL'
+    for j in 0..n {
+      let mut d = a[j * n + j];
+      for k in 0..j {
+        d -= a[j * n + k] * a[j * n + k];
+      }
+      if !(d > 0.0) {
+        return None;
+      }
+      let d = d.sqrt();
+      a[j * n + j] = d;
+
+      for i in (j + 1)..n {
+        let mut s = a[i * n + j];
+        for k in 0..j {
+          s -= a[i * n + k] * a[j * n + k];
+        }
+        a[i * n + j] = s / d;
+      }
+    }
+
+    let mut weights = vec![0.0; outputs * n];
+    let mut y = vec![0.0f64; n];
+    for k in 0..outputs {
+      // forward substitution, Ly = b
+      for i in 0..n {
+        let mut s = b[i * outputs + k];
+        for j in 0..i {
+          s -= a[i * n + j] * y[j];
+        }
+        y[i] = s / a[i * n + i];
+      }
+
+      // back substitution, L'w = y
+      for i in (0..n).rev() {
+        let mut s = y[i];
+        for j in (i + 1)..n {
+          s -= a[j * n + i] * y[j];
+        }
+        y[i] = s / a[i * n + i];
+      }
+
+      for i in 0..n {
+        weights[k * n + i] = y[i] as Float;
+      }
+    }
+
+    Some(Readout{
+      inputs: inputs,
+      outputs: outputs,
+      weights: weights,
+    })
+  }
+
+  pub fn predict(&self, state: &[Float]) -> Vec<Float> {
+    let n = self.inputs + 1;
+    (0..self.outputs).map(|k| {
+      let row = &self.weights[k * n..(k + 1) * n];
+      let mut y = row[self.inputs];
+      for (w, x) in row.iter().zip(state.iter()) {
+        y += w * x;
+      }
+      y
+    }).collect()
+  }
+
+  // Mean squared error of each output, `None` unless `states` and `targets`
+  // pair up into rows of this readout's shape.
+  pub fn error(&self, states: &[Vec<Float>], targets: &[Vec<Float>]) -> Option<Vec<Float>> {
+    if !self.pairs(states, targets) {
+      return None;
+    }
+
+    let mut error = vec![0.0; self.outputs];
+    for (state, target) in states.iter().zip(targets.iter()) {
+      for (e, (y, t)) in error.iter_mut().zip(self.predict(state).iter().zip(target.iter())) {
+        *e += (y - t) * (y - t);
+      }
+    }
+
+    let count = states.len() as Float;
+    for e in error.iter_mut() {
+      *e /= count;
+    }
+    Some(error)
+  }
+
+  // Squared correlation between each output and its target, the summands
+  // of the memory capacity of Jaeger (2002) when the targets are delayed
+  // copies of the input. `None` for input `error` rejects.
+  pub fn determination(&self, states: &[Vec<Float>], targets: &[Vec<Float>]) -> Option<Vec<Float>> {
+    if !self.pairs(states, targets) {
+      return None;
+    }
+
+    let predictions: Vec<Vec<Float>> = states.iter().map(|state| self.predict(state)).collect();
+    let count = states.len() as f64;
+
+    Some((0..self.outputs).map(|k| {
+      let (mut sy, mut st, mut syy, mut stt, mut syt) = (0.0f64, 0.0f64, 0.0f64, 0.0f64, 0.0f64);
+      for (y, t) in predictions.iter().zip(targets.iter()) {
+        let (y, t) = (y[k] as f64, t[k] as f64);
+        sy += y;
+        st += t;
+        syy += y * y;
+        stt += t * t;
+        syt += y * t;
+      }
+
+      let cov = syt / count - (sy / count) * (st / count);
+      let var_y = syy / count - (sy / count) * (sy / count);
+      let var_t = stt / count - (st / count) * (st / count);
+      if var_y <= 0.0 || var_t <= 0.0 {
+        0.0
+      } else {
+        (cov * cov / (var_y * var_t)) as Float
+      }
+    }).collect())
+  }
+
+  fn pairs(&self, states: &[Vec<Float>], targets: &[Vec<Float>]) -> bool {
+    !states.is_empty() && states.len() == targets.len() &&
+      states.iter().all(|s| s.len() == self.inputs) &&
+      targets.iter().all(|t| t.len() == self.outputs)
+  }
+}
@@ -0,0 +1,36 @@
+use Float;
+use std::default::Default;
+
+// Receptor kinetics from Izhikevich & Edelman (2008), "Large-scale model
+// of mammalian thalamocortical systems".
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ReceptorConfig {
+  // conductance decay time constants
+  pub tau_ampa: Float,
+  pub tau_nmda: Float,
+  pub tau_gaba_a: Float,
+  pub tau_gaba_b: Float,
+
+  // reversal potentials
+  pub e_ampa: Float,
+  pub e_nmda: Float,
+  pub e_gaba_a: Float,
+  pub e_gaba_b: Float,
+}
+
+impl Default for ReceptorConfig {
+  fn default() -> ReceptorConfig {
+    ReceptorConfig{
+      tau_ampa: 5.0,
+      tau_nmda: 150.0,
+      tau_gaba_a: 6.0,
+      tau_gaba_b: 150.0,
+      e_ampa: 0.0,
+      e_nmda: 0.0,
+      e_gaba_a: -70.0,
+      e_gaba_b: -90.0,
+    }
+  }
+}
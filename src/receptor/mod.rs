@@ -0,0 +1,9 @@
+pub use self::receptors::{Receptors, NONE, AMPA, NMDA, GABA_A, GABA_B};
+pub use self::config::ReceptorConfig;
+pub use self::neuron::ReceptorNeuron;
+pub use self::synapse::ReceptorSynapse;
+
+pub mod receptors;
+pub mod config;
+pub mod neuron;
+pub mod synapse;
@@ -0,0 +1,114 @@
+use Float;
+
+use neuron::Neuron;
+use receptor::config::ReceptorConfig;
+use receptor::receptors::{self, Receptors};
+
+// Fraction of NMDA channels not blocked by Mg2+ at potential `v`.
+pub fn mg_block(v: Float) -> Float {
+  let x = ((v + 80.0) / 60.0).powi(2);
+  x / (1.0 + x)
+}
+
+// Wraps a neuron with AMPA, NMDA, GABA-A and GABA-B receptor channels. A
+// spike opens each receptor its synapse targets by the magnitude of its
+// weight; spikes from synapses that do not name receptors open AMPA and
+// NMDA if excitatory and GABA-A and GABA-B if inhibitory. Every receptor
+// drives the wrapped neuron as a conductance towards its reversal
+// potential, the NMDA one gated by the Mg2+ block.
+#[derive(Debug, Clone, Copy)]
+pub struct ReceptorNeuron<N: Neuron> {
+  neuron: N,
+
+  g: [Float; receptors::COUNT],
+
+  // conductance arriving at the start of the next tick
+  g_next: [Float; receptors::COUNT],
+
+  tau: [Float; receptors::COUNT],
+  e_rev: [Float; receptors::COUNT],
+}
+
+impl<N: Neuron> ReceptorNeuron<N> {
+  pub fn new(config: ReceptorConfig, neuron: N) -> ReceptorNeuron<N> {
+    ReceptorNeuron{
+      neuron: neuron,
+      g: [0.0; receptors::COUNT],
+      g_next: [0.0; receptors::COUNT],
+      tau: [config.tau_ampa, config.tau_nmda, config.tau_gaba_a, config.tau_gaba_b],
+      e_rev: [config.e_ampa, config.e_nmda, config.e_gaba_a, config.e_gaba_b],
+    }
+  }
+
+  pub fn neuron(&self) -> &N {
+    &self.neuron
+  }
+
+  // Total conductance of the given receptors.
+  pub fn conductance(&self, receptors: Receptors) -> Float {
+    (0..receptors::COUNT)
+      .filter(|&r| receptors.has(r))
+      .fold(0.0, |g, r| g + self.g[r])
+  }
+}
+
+impl<N: Neuron> Neuron for ReceptorNeuron<N> {
+  fn recv(&mut self, v: Float) -> Float {
+    self.neuron.recv(v)
+  }
+
+  fn recv_at(&mut self, v: Float, offset: Float) -> Float {
+    self.recv_receptors(v, offset, receptors::NONE)
+  }
+
+  fn recv_receptors(&mut self, v: Float, offset: Float, receptors: Receptors) -> Float {
+    let receptors = if !receptors.is_empty() {
+      receptors
+    } else if v < 0.0 {
+      receptors::GABA_A | receptors::GABA_B
+    } else {
+      receptors::AMPA | receptors::NMDA
+    };
+
+    // Split the spike between this tick and the next, as the point
+    // neurons do with their input charge.
+    let g = v.abs();
+    for r in 0..receptors::COUNT {
+      if receptors.has(r) {
+        self.g[r] += g * (1.0 - offset);
+        self.g_next[r] += g * offset;
+      }
+    }
+    self.conductance(receptors)
+  }
+
+  fn recv_conductance(&mut self, g: Float, e_rev: Float) -> Float {
+    self.neuron.recv_conductance(g, e_rev)
+  }
+
+  fn tick(&mut self, tau: Float) {
+    let v = self.neuron.voltage();
+    for r in 0..receptors::COUNT {
+      let g = if receptors::NMDA.has(r) { self.g[r] * mg_block(v) } else { self.g[r] };
+      self.neuron.recv_conductance(g, self.e_rev[r]);
+    }
+    self.neuron.tick(tau);
+
+    for r in 0..receptors::COUNT {
+      self.g[r] = self.g[r] * (-tau / self.tau[r]).exp() + self.g_next[r];
+      self.g_next[r] = 0.0;
+    }
+  }
+
+  fn threshold(&mut self) -> Float {
+    self.neuron.threshold()
+  }
+
+  fn reset(&mut self) {
+    self.neuron.reset()
+  }
+
+  fn voltage(&self) -> Float {
+    self.neuron.voltage()
+  }
+}
@@ -0,0 +1,51 @@
+use std::ops::BitOr;
+
+// Set of postsynaptic receptor types targeted by a synapse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Receptors(u8);
+
+// Leaves the choice to the postsynaptic neuron.
+pub const NONE: Receptors = Receptors(0);
+
+// fast excitatory
+pub const AMPA: Receptors = Receptors(1);
+
+// slow excitatory, blocked by Mg2+ near rest
+pub const NMDA: Receptors = Receptors(2);
+
+// fast inhibitory
+pub const GABA_A: Receptors = Receptors(4);
+
+// slow inhibitory
+pub const GABA_B: Receptors = Receptors(8);
+
+pub const COUNT: usize = 4;
+
+impl Receptors {
+  pub fn is_empty(&self) -> bool {
+    self.0 == 0
+  }
+
+  pub fn contains(&self, other: Receptors) -> bool {
+    self.0 & other.0 == other.0
+  }
+
+  // Whether the `index`th receptor type is in the set.
+  pub fn has(&self, index: usize) -> bool {
+    self.0 & (1 << index) != 0
+  }
+}
+
+impl Default for Receptors {
+  fn default() -> Receptors {
+    NONE
+  }
+}
+
+impl BitOr for Receptors {
+  type Output = Receptors;
+
+  fn bitor(self, other: Receptors) -> Receptors {
+    Receptors(self.0 | other.0)
+  }
+}
@@ -0,0 +1,77 @@
+use Float;
+use synapse::Synapse;
+use receptor::receptors::Receptors;
+
+// Wraps any synapse so its spikes target the given postsynaptic receptors.
+#[derive(Debug, Clone, Copy)]
+pub struct ReceptorSynapse<S: Synapse> {
+  synapse: S,
+  receptors: Receptors,
+}
+
+impl<S: Synapse> ReceptorSynapse<S> {
+  pub fn new(receptors: Receptors, synapse: S) -> ReceptorSynapse<S> {
+    ReceptorSynapse{
+      synapse: synapse,
+      receptors: receptors,
+    }
+  }
+
+  pub fn synapse(&self) -> &S {
+    &self.synapse
+  }
+}
+
+impl<S: Synapse> Synapse for ReceptorSynapse<S> {
+  fn weight(&self) -> Float {
+    self.synapse.weight()
+  }
+
+  fn scale(&mut self, factor: Float) -> Float {
+    self.synapse.scale(factor)
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float {
+    self.synapse.set_weight(weight)
+  }
+
+  fn delay(&self) -> Float {
+    self.synapse.delay()
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.synapse.dendritic_delay()
+  }
+
+  fn is_voltage_dependent(&self) -> bool {
+    self.synapse.is_voltage_dependent()
+  }
+
+  fn receptors(&self) -> Receptors {
+    self.receptors
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float {
+    self.synapse.pre_recv(now)
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float {
+    self.synapse.post_recv(now)
+  }
+
+  fn post_voltage(&mut self, v: Float, now: Float) -> Float {
+    self.synapse.post_voltage(v, now)
+  }
+
+  fn modulate(&mut self, d: Float, now: Float) -> Float {
+    self.synapse.modulate(d, now)
+  }
+
+  fn teach(&mut self, now: Float) -> Float {
+    self.synapse.teach(now)
+  }
+
+  fn transmit(&mut self, now: Float) -> Float {
+    self.synapse.transmit(now)
+  }
+}
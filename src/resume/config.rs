@@ -0,0 +1,44 @@
+use Float;
+use std::default::Default;
+
+// Remote supervised method from Ponulak & Kasinski (2010), "Supervised
+// learning in spiking neural networks with ReSuMe".
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ReSuMeConfig {
+  pub weight: Float,
+  pub min: Float,
+  pub max: Float,
+
+  // learning rate
+  pub eta: Float,
+
+  // non-Hebbian term, adjusts the mean rate regardless of timing
+  pub a: Float,
+
+  // amplitude and time constant of the learning window
+  pub a_pos: Float,
+  pub tau: Float,
+
+  pub delay: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float,
+}
+
+impl Default for ReSuMeConfig {
+  fn default() -> ReSuMeConfig {
+    ReSuMeConfig{
+      weight: 0.0,
+      min: -20.0,
+      max: 20.0,
+      eta: 0.5,
+      a: 0.01,
+      a_pos: 1.0,
+      tau: 5.0,
+      delay: 1.0,
+      dendritic: 0.0,
+    }
+  }
+}
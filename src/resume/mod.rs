@@ -0,0 +1,5 @@
+pub use self::config::ReSuMeConfig;
+pub use self::synapse::ReSuMeSynapse;
+
+pub mod config;
+pub mod synapse;
@@ -0,0 +1,93 @@
+use Float;
+use synapse::Synapse;
+use trace::Trace;
+use resume::config::ReSuMeConfig;
+
+// Potentiates at every teacher spike and depresses at every actual
+// postsynaptic spike by eta * (a + x), with x the trace left by recent
+// presynaptic spikes, each adding a_pos. Learning stops once the neuron
+// fires exactly when told to.
+#[derive(Debug, Clone, Copy)]
+pub struct ReSuMeSynapse<T: Trace> {
+  weight: Float,
+
+  min: Float,
+  max: Float,
+
+  eta: Float,
+  a: Float,
+  a_pos: Float,
+
+  pre_trace: T,
+
+  delay: Float,
+  dendritic: Float,
+}
+
+impl<T: Trace> ReSuMeSynapse<T> {
+  pub fn new(config: ReSuMeConfig) -> ReSuMeSynapse<T> {
+    return ReSuMeSynapse{
+      weight: config.weight,
+      min: config.min,
+      max: config.max,
+      eta: config.eta,
+      a: config.a,
+      a_pos: config.a_pos,
+      pre_trace: T::new(config.tau, true),
+      delay: config.delay,
+      dendritic: config.dendritic,
+    }
+  }
+
+  fn integrate(&mut self, delta: Float) {
+    self.weight = self.weight + delta;
+    if self.weight > self.max {
+      self.weight = self.max;
+    } else if self.weight < self.min {
+      self.weight = self.min;
+    }
+  }
+}
+
+impl<T: Trace> Synapse for ReSuMeSynapse<T> {
+  fn weight(&self) -> Float {
+    self.weight
+  }
+
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
+    self.delay
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float { // delta
+    self.pre_trace.update(self.a_pos, now);
+    0.0
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float { // delta
+    let delta = -self.eta * (self.a + self.pre_trace.read(now));
+    self.integrate(delta);
+    delta
+  }
+
+  fn teach(&mut self, now: Float) -> Float { // delta
+    let delta = self.eta * (self.a + self.pre_trace.read(now));
+    self.integrate(delta);
+    delta
+  }
+}
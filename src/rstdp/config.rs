@@ -0,0 +1,59 @@
+use Float;
+use std::default::Default;
+
+// Defaults follow Izhikevich (2007), "Solving the distal reward problem
+// through linkage of STDP and dopamine signaling", with the STDP amplitudes
+// and tonic dopamine level scaled to the weight range.
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct RSTDPConfig {
+  pub weight: Float,
+  pub min: Float,
+  pub max: Float,
+
+  // STDP learning rates, added to the eligibility trace
+  pub n_pos: Float,
+  pub n_neg: Float,
+
+  // STDP window time constants
+  pub tau_pos: Float,
+  pub tau_neg: Float,
+
+  // eligibility trace time constant
+  pub tau_c: Float,
+
+  // dopamine uptake time constant
+  pub tau_d: Float,
+
+  // tonic dopamine concentration
+  pub tonic: Float,
+
+  // all-to-all vs. nearest-neighbor interactions
+  pub continuous: bool,
+
+  pub delay: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float
+}
+
+impl Default for RSTDPConfig {
+  fn default() -> RSTDPConfig {
+    RSTDPConfig{
+      weight: 1.0,
+      min: 0.0,
+      max: 4.0,
+      n_pos: 0.1,
+      n_neg: -0.15,
+      tau_pos: 20.0,
+      tau_neg: 20.0,
+      tau_c: 1000.0,
+      tau_d: 200.0,
+      tonic: 0.0001,
+      continuous: false,
+      delay: 1.0,
+      dendritic: 0.0
+    }
+  }
+}
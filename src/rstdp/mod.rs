@@ -0,0 +1,5 @@
+pub use self::config::RSTDPConfig;
+pub use self::synapse::RSTDPSynapse;
+
+pub mod config;
+pub mod synapse;
@@ -0,0 +1,162 @@
+use Float;
+use synapse::Synapse;
+use trace::Trace;
+use rstdp::config::RSTDPConfig;
+
+// Reward-modulated STDP. Spike pairings accumulate into a decaying
+// eligibility trace `c` and the weight only changes in proportion to
+// `c` times the dopamine concentration `d`. Both decay exponentially, so
+// the weight is integrated in closed form whenever an event arrives or a
+// spike is sent while learning.
+#[derive(Debug, Clone, Copy)]
+pub struct RSTDPSynapse<T: Trace> {
+  weight: Float,
+
+  min: Float,
+  max: Float,
+
+  pre_trace: T,
+  post_trace: T,
+
+  n_pos: Float,
+  n_neg: Float,
+
+  // eligibility
+  c: Float,
+  tau_c: Float,
+
+  // phasic dopamine
+  d: Float,
+  tau_d: Float,
+
+  tonic: Float,
+
+  last_time: Float,
+
+  delay: Float,
+  dendritic: Float,
+}
+
+impl<T: Trace> RSTDPSynapse<T> {
+  pub fn new(config: RSTDPConfig) -> RSTDPSynapse<T> {
+    return RSTDPSynapse{
+      pre_trace: T::new(config.tau_pos, config.continuous),
+      post_trace: T::new(config.tau_neg, config.continuous),
+      weight: config.weight,
+      n_pos: config.n_pos,
+      n_neg: config.n_neg,
+      c: 0.0,
+      tau_c: config.tau_c,
+      d: 0.0,
+      tau_d: config.tau_d,
+      tonic: config.tonic,
+      last_time: 0.0,
+      delay: config.delay,
+      dendritic: config.dendritic,
+      min: config.min,
+      max: config.max,
+    }
+  }
+
+  pub fn eligibility(&self) -> Float {
+    self.c
+  }
+
+  // Weight as of `now`. Dopamine keeps changing it between events, so
+  // `weight` alone is only current as of the last one.
+  pub fn weight_at(&self, now: Float) -> Float {
+    let dt = now - self.last_time;
+    if dt <= 0.0 {
+      return self.weight;
+    }
+    (self.weight + self.accrued(dt)).max(self.min).min(self.max)
+  }
+
+  // Integral of dw/dt = c * (tonic + d) over the `dt` after the last event.
+  fn accrued(&self, dt: Float) -> Float {
+    let decay_c = (-dt / self.tau_c).exp();
+    let decay_d = (-dt / self.tau_d).exp();
+
+    // c and d decay together with the combined time constant
+    let tau_cd = (self.tau_c * self.tau_d) / (self.tau_c + self.tau_d);
+
+    self.c * (self.tonic * self.tau_c * (1.0 - decay_c) +
+      self.d * tau_cd * (1.0 - decay_c * decay_d))
+  }
+
+  fn integrate(&mut self, delta: Float) {
+    self.weight = self.weight + delta;
+    if self.weight > self.max {
+      self.weight = self.max;
+    } else if self.weight < self.min {
+      self.weight = self.min;
+    }
+  }
+}
+
+impl<T: Trace> Synapse for RSTDPSynapse<T> {
+  fn weight(&self) -> Float {
+    self.weight
+  }
+
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
+    self.delay
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float { // delta
+    let delta = self.settle(now);
+
+    // Depression is tagged rather than applied
+    self.pre_trace.update(1.0, now);
+    self.c += self.n_neg * self.post_trace.read(now);
+    delta
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float { // delta
+    let delta = self.settle(now);
+
+    // Potentiation is tagged rather than applied
+    self.post_trace.update(1.0, now);
+    self.c += self.n_pos * self.pre_trace.read(now);
+    delta
+  }
+
+  fn modulate(&mut self, d: Float, now: Float) -> Float { // delta
+    let delta = self.settle(now);
+    self.d += d;
+    delta
+  }
+
+  // Integrates dw/dt = c * (tonic + d) up to `now`.
+  fn settle(&mut self, now: Float) -> Float { // delta
+    let dt = now - self.last_time;
+    if dt <= 0.0 {
+      return 0.0;
+    }
+    self.last_time = now;
+
+    let delta = self.accrued(dt);
+    self.c *= (-dt / self.tau_c).exp();
+    self.d *= (-dt / self.tau_d).exp();
+
+    let weight = self.weight;
+    self.integrate(delta);
+    self.weight - weight
+  }
+}
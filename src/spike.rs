@@ -1,7 +1,28 @@
 use Float;
+use receptor::Receptors;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpikeKind {
+  // Delivers `v` to the postsynaptic neuron.
+  Transmit,
+
+  // Presynaptic spike reaching the synapse after the axonal delay.
+  Pre,
+
+  // Postsynaptic spike reaching the synapse after the dendritic delay.
+  Post,
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct Spike {
+  pub kind: SpikeKind,
+  pub synapse_id: usize,
   pub recvr_id: usize,
-  pub v: Float
+  pub v: Float,
+
+  // Postsynaptic receptors targeted by a `Transmit` spike.
+  pub receptors: Receptors,
+
+  // Arrival time within the tick, in [0, 1).
+  pub offset: Float
 }
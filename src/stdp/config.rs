@@ -22,7 +22,11 @@ pub struct STDPConfig {
   // implement weight-dependent synaptic scaling
   pub scale: bool,
 
-  pub delay: usize
+  // In ticks. A float since FFI version 0.2.0, it was a usize before.
+  pub delay: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float
 }
 
 impl Default for STDPConfig {
@@ -39,7 +43,8 @@ impl Default for STDPConfig {
       a_neg: 1.0,
       continuous: false,
       scale: false,
-      delay: 1
+      delay: 1.0,
+      dendritic: 0.0
     }
   }
 }
@@ -19,7 +19,8 @@ pub struct STDPSynapse<T: Trace> {
   a_pos: Float,
   a_neg: Float,
 
-  delay: usize,
+  delay: Float,
+  dendritic: Float,
 
   scale: bool,
 }
@@ -35,6 +36,7 @@ impl<T: Trace> STDPSynapse<T> {
       a_pos: config.a_pos,
       a_neg: config.a_neg,
       delay: config.delay,
+      dendritic: config.dendritic,
       scale: config.scale,
       min: config.min,
       max: config.max,
@@ -72,10 +74,26 @@ impl<T: Trace> Synapse for STDPSynapse<T> {
     self.weight
   }
 
-  fn delay(&self) -> usize {
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
     self.delay
   }
 
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
   fn pre_recv(&mut self, now: Float) -> Float { // delta
     // Pre-synaptic spike leaves a trace which increases
     // by an amount a+(x) at the moment of spike arrival and decays
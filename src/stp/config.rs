@@ -0,0 +1,43 @@
+use Float;
+use std::default::Default;
+
+// Tsodyks-Markram short-term plasticity. Presets follow Tsodyks, Uziel &
+// Markram (2000), "Synchrony generation in recurrent networks with
+// frequency-dependent synapses".
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct STPConfig {
+  // utilization of synaptic efficacy
+  pub u: Float,
+
+  // recovery time constant of the depleted resources
+  pub tau_rec: Float,
+
+  // facilitation time constant, zero disables facilitation
+  pub tau_facil: Float,
+}
+
+impl Default for STPConfig {
+  fn default() -> STPConfig {
+    STPConfig::depressing()
+  }
+}
+
+impl STPConfig {
+  pub fn depressing() -> STPConfig {
+    STPConfig{
+      u: 0.5,
+      tau_rec: 800.0,
+      tau_facil: 0.0,
+    }
+  }
+
+  pub fn facilitating() -> STPConfig {
+    STPConfig{
+      u: 0.03,
+      tau_rec: 130.0,
+      tau_facil: 530.0,
+    }
+  }
+}
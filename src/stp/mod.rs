@@ -0,0 +1,5 @@
+pub use self::config::STPConfig;
+pub use self::synapse::STPSynapse;
+
+pub mod config;
+pub mod synapse;
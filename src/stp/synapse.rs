@@ -0,0 +1,114 @@
+use Float;
+use synapse::Synapse;
+use receptor::Receptors;
+use stp::config::STPConfig;
+
+// Wraps any synapse with Tsodyks-Markram short-term dynamics. Each
+// transmitted spike carries the wrapped synapse's efficacy scaled by the
+// utilization `u` and the fraction of available resources `x`. Long-term
+// plasticity is left to the wrapped synapse.
+#[derive(Debug, Clone, Copy)]
+pub struct STPSynapse<S: Synapse> {
+  synapse: S,
+
+  u_base: Float,
+  tau_rec: Float,
+  tau_facil: Float,
+
+  // utilization and available resources at the last spike
+  u: Float,
+  x: Float,
+
+  last_time: Float,
+  spiked: bool,
+}
+
+impl<S: Synapse> STPSynapse<S> {
+  pub fn new(config: STPConfig, synapse: S) -> STPSynapse<S> {
+    return STPSynapse{
+      synapse: synapse,
+      u_base: config.u,
+      tau_rec: config.tau_rec,
+      tau_facil: config.tau_facil,
+      u: config.u,
+      x: 1.0,
+      last_time: 0.0,
+      spiked: false,
+    }
+  }
+
+  pub fn synapse(&self) -> &S {
+    &self.synapse
+  }
+}
+
+impl<S: Synapse> Synapse for STPSynapse<S> {
+  fn weight(&self) -> Float {
+    self.synapse.weight()
+  }
+
+  fn scale(&mut self, factor: Float) -> Float {
+    self.synapse.scale(factor)
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float {
+    self.synapse.set_weight(weight)
+  }
+
+  fn delay(&self) -> Float {
+    self.synapse.delay()
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.synapse.dendritic_delay()
+  }
+
+  fn is_voltage_dependent(&self) -> bool {
+    self.synapse.is_voltage_dependent()
+  }
+
+  fn receptors(&self) -> Receptors {
+    self.synapse.receptors()
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float {
+    self.synapse.pre_recv(now)
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float {
+    self.synapse.post_recv(now)
+  }
+
+  fn post_voltage(&mut self, v: Float, now: Float) -> Float {
+    self.synapse.post_voltage(v, now)
+  }
+
+  fn modulate(&mut self, d: Float, now: Float) -> Float {
+    self.synapse.modulate(d, now)
+  }
+
+  fn teach(&mut self, now: Float) -> Float {
+    self.synapse.teach(now)
+  }
+
+  fn transmit(&mut self, now: Float) -> Float {
+    if self.spiked {
+      let dt = now - self.last_time;
+
+      // Resources used by the previous spike recover towards 1
+      self.x = 1.0 + (self.x - self.u * self.x - 1.0) * (-dt / self.tau_rec).exp();
+
+      // Utilization jumps at every spike and decays back to baseline
+      self.u = if self.tau_facil > 0.0 {
+        self.u_base + self.u * (1.0 - self.u_base) * (-dt / self.tau_facil).exp()
+      } else {
+        self.u_base
+      };
+    }
+
+    self.spiked = true;
+    self.last_time = now;
+
+    self.synapse.transmit(now) * self.u * self.x
+  }
+}
@@ -0,0 +1,36 @@
+use Float;
+use std::default::Default;
+use std::f32;
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct StructuralConfig {
+  // ticks between structural updates
+  pub period: usize,
+
+  // synapses whose weight magnitude stays below `prune_threshold` for
+  // `prune_duration` ticks are removed
+  pub prune_threshold: Float,
+  pub prune_duration: Float,
+
+  // chance per update that a candidate pair without a synapse gets one
+  pub growth_probability: Float,
+
+  // candidate pairs further apart than this never connect
+  pub max_distance: Float,
+
+  pub seed: usize,
+}
+
+impl Default for StructuralConfig {
+  fn default() -> StructuralConfig {
+    StructuralConfig{
+      period: 100,
+      prune_threshold: 0.1,
+      prune_duration: 1000.0,
+      growth_probability: 0.001,
+      max_distance: f32::INFINITY,
+      seed: 1,
+    }
+  }
+}
@@ -0,0 +1,5 @@
+pub use self::config::StructuralConfig;
+pub use self::rule::{StructuralRule, StructuralReport};
+
+pub mod config;
+pub mod rule;
@@ -0,0 +1,123 @@
+extern crate rand;
+extern crate vec_map;
+
+use self::rand::{Rng, SeedableRng, StdRng};
+use self::vec_map::VecMap;
+
+use Float;
+use synapse::Synapse;
+use structural::config::StructuralConfig;
+
+// Synapses created and pruned by structural plasticity since the last
+// time the report was taken.
+#[derive(Debug, Clone, Default)]
+pub struct StructuralReport {
+  pub created: Vec<usize>,
+  pub pruned: Vec<usize>,
+}
+
+// Rewires the connections from one population to another. New synapses
+// come from `factory`, called with the presynaptic and postsynaptic ids.
+pub struct StructuralRule<S: Synapse> {
+  sources: Vec<usize>,
+  targets: Vec<usize>,
+  is_source: VecMap<bool>,
+
+  factory: Box<Fn(usize, usize) -> S>,
+
+  period: usize,
+  prune_threshold: Float,
+  prune_duration: Float,
+  growth_probability: Float,
+  max_distance: Float,
+
+  // time each weak synapse was first seen below the pruning threshold
+  weak_since: VecMap<Float>,
+
+  rng: StdRng,
+  ticks: usize,
+}
+
+impl<S: Synapse> StructuralRule<S> {
+  pub fn new(sources: &[usize], targets: &[usize], config: StructuralConfig, factory: Box<Fn(usize, usize) -> S>) -> StructuralRule<S> {
+    let mut is_source = VecMap::new();
+    for &neuron_id in sources.iter() {
+      is_source.insert(neuron_id, true);
+    }
+
+    let seed: &[_] = &[config.seed];
+    StructuralRule{
+      sources: sources.to_vec(),
+      targets: targets.to_vec(),
+      is_source: is_source,
+      factory: factory,
+      period: config.period,
+      prune_threshold: config.prune_threshold,
+      prune_duration: config.prune_duration,
+      growth_probability: config.growth_probability,
+      max_distance: config.max_distance,
+      weak_since: VecMap::new(),
+      rng: SeedableRng::from_seed(seed),
+      ticks: 0,
+    }
+  }
+
+  pub fn sources(&self) -> &[usize] {
+    &self.sources
+  }
+
+  pub fn targets(&self) -> &[usize] {
+    &self.targets
+  }
+
+  pub fn is_source(&self, neuron_id: usize) -> bool {
+    self.is_source.contains_key(&neuron_id)
+  }
+
+  // Advance by one tick. Returns true when an update is due.
+  pub fn step(&mut self) -> bool {
+    self.ticks += 1;
+    if self.ticks < self.period {
+      return false;
+    }
+    self.ticks = 0;
+    true
+  }
+
+  // Notes the weight of a synapse every tick, so it counts as weak from the
+  // first tick it drops below the pruning threshold.
+  pub fn track(&mut self, synapse_id: usize, weight: Float, now: Float) {
+    if weight.abs() >= self.prune_threshold {
+      self.weak_since.remove(&synapse_id);
+    } else if !self.weak_since.contains_key(&synapse_id) {
+      self.weak_since.insert(synapse_id, now);
+    }
+  }
+
+  // Whether a synapse has been weak for long enough to be pruned.
+  pub fn should_prune(&self, synapse_id: usize, now: Float) -> bool {
+    match self.weak_since.get(&synapse_id) {
+      Some(&since) => now - since >= self.prune_duration,
+      None => false,
+    }
+  }
+
+  pub fn forget(&mut self, synapse_id: usize) {
+    self.weak_since.remove(&synapse_id);
+  }
+
+  // Draws whether an unconnected pair `distance` apart grows a synapse.
+  // Pairs without positions are not limited by distance.
+  pub fn should_grow(&mut self, distance: Option<Float>) -> bool {
+    if let Some(d) = distance {
+      if d > self.max_distance {
+        return false;
+      }
+    }
+    self.rng.gen::<Float>() < self.growth_probability
+  }
+
+  pub fn create(&self, sendr_id: usize, recvr_id: usize) -> S {
+    (self.factory)(sendr_id, recvr_id)
+  }
+}
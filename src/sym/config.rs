@@ -8,9 +8,14 @@ pub struct SymConfig {
   pub a_sym: Float,
   pub tau_a: Float,
   pub tau_b: Float,
-  pub delay: usize,
+
+  // In ticks. A float since FFI version 0.2.0, it was a usize before.
+  pub delay: Float,
   pub min: Float,
   pub max: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float,
 }
 
 impl Default for SymConfig {
@@ -20,9 +25,10 @@ impl Default for SymConfig {
       a_sym: 0.05,
       tau_a: 10.0,
       tau_b: 10.0,
-      delay: 1,
+      delay: 1.0,
       min: -10.0,
       max: 10.0,
+      dendritic: 0.0,
     }
   }
 }
@@ -15,7 +15,8 @@ pub struct SymSynapse {
   tau_a: Float,
   tau_b: Float,
 
-  delay: usize,
+  delay: Float,
+  dendritic: Float,
 
   pre_time: Float,
   post_time: Float,
@@ -29,6 +30,7 @@ impl SymSynapse {
       tau_a: config.tau_a,
       tau_b: config.tau_b,
       delay: config.delay,
+      dendritic: config.dendritic,
       min: config.min,
       max: config.max,
       pre_time: 0.0,
@@ -56,10 +58,26 @@ impl Synapse for SymSynapse {
     self.weight
   }
 
-  fn delay(&self) -> usize {
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
     self.delay
   }
 
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
   fn pre_recv(&mut self, now: Float) -> Float { // delta
       self.pre_time = now;
 
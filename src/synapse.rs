@@ -1,9 +1,71 @@
 use Float;
+use receptor::Receptors;
 
 // Synapse represents a connection with support for delayed and weighted outputs. The weight update mechanism (e.g. STDP) is up to the specific implementation.
 pub trait Synapse {
   fn pre_recv(&mut self, now: Float) -> Float;
   fn post_recv(&mut self, now: Float) -> Float;
   fn weight(&self) -> Float;
-  fn delay(&self) -> usize;
+
+  // Efficacy of a spike sent at `now`. Synapses with short-term dynamics
+  // update their state here.
+  fn transmit(&mut self, _now: Float) -> Float {
+    self.weight()
+  }
+
+  // Multiply the weight by `factor`, clamped to the synapse's bounds, and
+  // return the change. Used by homeostatic scaling.
+  fn scale(&mut self, factor: Float) -> Float {
+    let w = self.weight() * factor;
+    self.set_weight(w)
+  }
+
+  // Overwrite the weight, clamped to the synapse's bounds, and return the
+  // change. Used to load trained weights, so every synapse must support it.
+  fn set_weight(&mut self, weight: Float) -> Float;
+
+  // Conduction delay in ticks. Must be at least one tick but need not be
+  // a whole number; the fractional part is delivered as the spike's offset
+  // within the arrival tick.
+  fn delay(&self) -> Float;
+
+  // Portion of `delay` spent travelling back up the dendrite. Plasticity sees
+  // the presynaptic spike after the axonal remainder and the postsynaptic
+  // spike after this.
+  fn dendritic_delay(&self) -> Float {
+    0.0
+  }
+
+  // Voltage-dependent synapses receive the postsynaptic membrane potential
+  // every tick through `post_voltage`. Checked once when added to a network.
+  fn is_voltage_dependent(&self) -> bool {
+    false
+  }
+
+  // Postsynaptic receptors targeted by transmitted spikes. An empty set
+  // leaves the choice to the postsynaptic neuron.
+  fn receptors(&self) -> Receptors {
+    Receptors::default()
+  }
+
+  fn post_voltage(&mut self, _v: Float, _now: Float) -> Float {
+    0.0
+  }
+
+  // Supervised synapses receive the times at which a teacher wants the
+  // postsynaptic neuron to fire.
+  fn teach(&mut self, _now: Float) -> Float {
+    0.0
+  }
+
+  // Neuromodulated synapses receive dopamine `d` released at `now`.
+  fn modulate(&mut self, _d: Float, _now: Float) -> Float {
+    0.0
+  }
+
+  // Synapses whose weight keeps changing between events bring it up to
+  // `now`. Called with learning enabled before a spike is sent.
+  fn settle(&mut self, _now: Float) -> Float {
+    0.0
+  }
 }
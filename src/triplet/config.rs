@@ -0,0 +1,87 @@
+use Float;
+use std::default::Default;
+
+// Parameters follow Pfister & Gerstner (2006), "Triplets of Spikes in a Model
+// of Spike Timing-Dependent Plasticity", fitted to visual cortex data.
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct TripletSTDPConfig {
+  pub weight: Float,
+  pub min: Float,
+  pub max: Float,
+
+  // pair amplitudes
+  pub a2_pos: Float,
+  pub a2_neg: Float,
+
+  // triplet amplitudes
+  pub a3_pos: Float,
+  pub a3_neg: Float,
+
+  // fast pre (r1) and post (o1) trace time constants
+  pub tau_pos: Float,
+  pub tau_neg: Float,
+
+  // slow pre (r2) and post (o2) trace time constants
+  pub tau_x: Float,
+  pub tau_y: Float,
+
+  // all-to-all vs. nearest-spike interactions
+  pub continuous: bool,
+
+  pub delay: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float
+}
+
+impl Default for TripletSTDPConfig {
+  fn default() -> TripletSTDPConfig {
+    TripletSTDPConfig::all_to_all_minimal()
+  }
+}
+
+impl TripletSTDPConfig {
+  // Minimal all-to-all model. Without a triplet depression term `tau_x` is
+  // unused.
+  pub fn all_to_all_minimal() -> TripletSTDPConfig {
+    TripletSTDPConfig{
+      weight: 1.0,
+      min: 0.0,
+      max: 2.0,
+      a2_pos: 5e-10,
+      a2_neg: 7e-3,
+      a3_pos: 6.2e-3,
+      a3_neg: 0.0,
+      tau_pos: 16.8,
+      tau_neg: 33.7,
+      tau_x: 101.0,
+      tau_y: 125.0,
+      continuous: true,
+      delay: 1.0,
+      dendritic: 0.0
+    }
+  }
+
+  // Minimal nearest-spike model. Without a triplet depression term `tau_x`
+  // is unused.
+  pub fn nearest_spike_minimal() -> TripletSTDPConfig {
+    TripletSTDPConfig{
+      weight: 1.0,
+      min: 0.0,
+      max: 2.0,
+      a2_pos: 8.8e-11,
+      a2_neg: 6.6e-3,
+      a3_pos: 5.3e-2,
+      a3_neg: 0.0,
+      tau_pos: 16.8,
+      tau_neg: 33.7,
+      tau_x: 714.0,
+      tau_y: 40.0,
+      continuous: false,
+      delay: 1.0,
+      dendritic: 0.0
+    }
+  }
+}
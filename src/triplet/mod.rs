@@ -0,0 +1,5 @@
+pub use self::config::TripletSTDPConfig;
+pub use self::synapse::TripletSTDPSynapse;
+
+pub mod config;
+pub mod synapse;
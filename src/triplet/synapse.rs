@@ -0,0 +1,109 @@
+use Float;
+use synapse::Synapse;
+use trace::Trace;
+use triplet::config::TripletSTDPConfig;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TripletSTDPSynapse<T: Trace> {
+  weight: Float,
+
+  min: Float,
+  max: Float,
+
+  a2_pos: Float,
+  a2_neg: Float,
+  a3_pos: Float,
+  a3_neg: Float,
+
+  // presynaptic fast (r1) and slow (r2) traces
+  r1: T,
+  r2: T,
+
+  // postsynaptic fast (o1) and slow (o2) traces
+  o1: T,
+  o2: T,
+
+  delay: Float,
+  dendritic: Float,
+}
+
+impl<T: Trace> TripletSTDPSynapse<T> {
+  pub fn new(config: TripletSTDPConfig) -> TripletSTDPSynapse<T> {
+    return TripletSTDPSynapse{
+      r1: T::new(config.tau_pos, config.continuous),
+      r2: T::new(config.tau_x, config.continuous),
+      o1: T::new(config.tau_neg, config.continuous),
+      o2: T::new(config.tau_y, config.continuous),
+      weight: config.weight,
+      a2_pos: config.a2_pos,
+      a2_neg: config.a2_neg,
+      a3_pos: config.a3_pos,
+      a3_neg: config.a3_neg,
+      delay: config.delay,
+      dendritic: config.dendritic,
+      min: config.min,
+      max: config.max,
+    }
+  }
+
+  fn integrate(&mut self, delta: Float) {
+    self.weight = self.weight + delta;
+    if self.weight > self.max {
+      self.weight = self.max;
+    } else if self.weight < self.min {
+      self.weight = self.min;
+    }
+  }
+}
+
+impl<T: Trace> Synapse for TripletSTDPSynapse<T> {
+  fn weight(&self) -> Float {
+    self.weight
+  }
+
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.weight * (factor - 1.0);
+    self.integrate(delta);
+    delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = weight - self.weight;
+    self.integrate(delta);
+    delta
+  }
+
+  fn delay(&self) -> Float {
+    self.delay
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float { // delta
+    // Weight is depressed by the fast post trace, boosted by the slow pre
+    // trace left by earlier presynaptic spikes (read before this spike
+    // is added).
+    let r2 = self.r2.read(now);
+    let delta = -1.0 * self.o1.read(now) * (self.a2_neg + self.a3_neg * r2);
+    self.integrate(delta);
+
+    self.r1.update(1.0, now);
+    self.r2.update(1.0, now);
+    delta
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float { // delta
+    // Weight is potentiated by the fast pre trace, boosted by the slow
+    // post trace left by earlier postsynaptic spikes (read before this
+    // spike is added).
+    let o2 = self.o2.read(now);
+    let delta = self.r1.read(now) * (self.a2_pos + self.a3_pos * o2);
+    self.integrate(delta);
+
+    self.o1.update(1.0, now);
+    self.o2.update(1.0, now);
+    delta
+  }
+}
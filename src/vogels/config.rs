@@ -0,0 +1,43 @@
+use Float;
+use std::default::Default;
+
+// Inhibitory plasticity from Vogels, Sprekeler, Zenke, Clopath & Gerstner
+// (2011), "Inhibitory plasticity balances excitation and inhibition in
+// sensory pathways and memory networks".
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct VogelsConfig {
+  // inhibitory strength, the synapse's weight is its negative. Defaults
+  // are scaled for conductance-based neurons.
+  pub weight: Float,
+  pub max: Float,
+
+  // learning rate
+  pub eta: Float,
+
+  // time constant of the symmetric STDP window
+  pub tau: Float,
+
+  // postsynaptic firing rate in spikes per tick the synapse drives towards
+  pub target_rate: Float,
+
+  pub delay: Float,
+
+  // fraction of `delay` that is dendritic, the rest is axonal
+  pub dendritic: Float,
+}
+
+impl Default for VogelsConfig {
+  fn default() -> VogelsConfig {
+    VogelsConfig{
+      weight: 0.1,
+      max: 5.0,
+      eta: 0.005,
+      tau: 20.0,
+      target_rate: 0.005,
+      delay: 1.0,
+      dendritic: 0.0,
+    }
+  }
+}
@@ -0,0 +1,5 @@
+pub use self::config::VogelsConfig;
+pub use self::synapse::VogelsSynapse;
+
+pub mod config;
+pub mod synapse;
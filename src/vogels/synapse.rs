@@ -0,0 +1,92 @@
+use Float;
+use synapse::Synapse;
+use trace::Trace;
+use vogels::config::VogelsConfig;
+
+// Symmetric inhibitory STDP. Near-coincident pre and post spikes strengthen
+// inhibition while every presynaptic spike weakens it by a constant
+// `alpha`, so inhibition grows until the postsynaptic rate settles at
+// alpha / (2 * tau).
+#[derive(Debug, Clone, Copy)]
+pub struct VogelsSynapse<T: Trace> {
+  // inhibitory strength, always non-negative
+  strength: Float,
+  max: Float,
+
+  eta: Float,
+
+  // depression per presynaptic spike, 2 * target_rate * tau
+  alpha: Float,
+
+  pre_trace: T,
+  post_trace: T,
+
+  delay: Float,
+  dendritic: Float,
+}
+
+impl<T: Trace> VogelsSynapse<T> {
+  pub fn new(config: VogelsConfig) -> VogelsSynapse<T> {
+    return VogelsSynapse{
+      strength: config.weight,
+      max: config.max,
+      eta: config.eta,
+      alpha: 2.0 * config.target_rate * config.tau,
+      pre_trace: T::new(config.tau, true),
+      post_trace: T::new(config.tau, true),
+      delay: config.delay,
+      dendritic: config.dendritic,
+    }
+  }
+
+  fn integrate(&mut self, delta: Float) {
+    self.strength = self.strength + delta;
+    if self.strength > self.max {
+      self.strength = self.max;
+    } else if self.strength < 0.0 {
+      self.strength = 0.0;
+    }
+  }
+}
+
+impl<T: Trace> Synapse for VogelsSynapse<T> {
+  fn weight(&self) -> Float {
+    -self.strength
+  }
+
+  fn scale(&mut self, factor: Float) -> Float { // delta
+    let delta = self.strength * (factor - 1.0);
+    self.integrate(delta);
+    -delta
+  }
+
+  fn set_weight(&mut self, weight: Float) -> Float { // delta
+    let delta = -weight - self.strength;
+    self.integrate(delta);
+    -delta
+  }
+
+  fn delay(&self) -> Float {
+    self.delay
+  }
+
+  fn dendritic_delay(&self) -> Float {
+    self.delay * self.dendritic
+  }
+
+  fn pre_recv(&mut self, now: Float) -> Float { // delta
+    self.pre_trace.update(1.0, now);
+
+    let delta = self.eta * (self.post_trace.read(now) - self.alpha);
+    self.integrate(delta);
+    -delta
+  }
+
+  fn post_recv(&mut self, now: Float) -> Float { // delta
+    self.post_trace.update(1.0, now);
+
+    let delta = self.eta * self.pre_trace.read(now);
+    self.integrate(delta);
+    -delta
+  }
+}
@@ -0,0 +1,189 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+use neural::Network;
+use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
+use neural::stdp::STDPSynapse;
+use neural::traces::ExpTrace;
+use neural::encoding::{Event, fill};
+use neural::decoding::SpikeMonitor;
+use neural::aer::{AerConfig, AerEvent, AerStream, read_aedat, open_aedat, read_bin, open_bin};
+
+fn write_file(name: &str, data: &[u8]) -> PathBuf {
+  let path = env::temp_dir().join(name);
+  File::create(&path).unwrap().write_all(data).unwrap();
+  path
+}
+
+// N-MNIST style event: x, y, polarity and a 23-bit timestamp
+fn bin_event(x: u8, y: u8, polarity: bool, timestamp: u32) -> Vec<u8> {
+  vec![
+    x,
+    y,
+    (if polarity { 0x80 } else { 0 }) | ((timestamp >> 16) & 0x7f) as u8,
+    (timestamp >> 8) as u8,
+    timestamp as u8,
+  ]
+}
+
+// DVS128 address, x mirrored and ON events with bit 0 clear
+fn dvs_address(x: u32, y: u32, polarity: bool) -> u32 {
+  (y << 8) | ((127 - x) << 1) | if polarity { 0 } else { 1 }
+}
+
+fn event(x: u16, y: u16, polarity: bool, timestamp: u64) -> AerEvent {
+  AerEvent{
+    x: x,
+    y: y,
+    polarity: polarity,
+    timestamp: timestamp,
+  }
+}
+
+#[test]
+fn test_aer_bin() {
+  let mut data = Vec::new();
+  data.extend(bin_event(3, 4, true, 10));
+  data.extend(bin_event(33, 0, false, 8000));
+  data.extend(bin_event(0, 240, false, 0)); // overflow
+  data.extend(bin_event(1, 2, true, 5));
+
+  let path = write_file("neural_test_aer.bin", &data);
+  let events = open_bin(&path).unwrap();
+  fs::remove_file(&path).ok();
+
+  assert_eq!(events, vec![
+    event(3, 4, true, 10),
+    event(33, 0, false, 8000),
+    event(1, 2, true, 8192 + 5),
+  ]);
+
+  // a partial event is an error
+  assert!(read_bin(&data[..7]).is_err());
+}
+
+#[test]
+fn test_aer_aedat() {
+  let mut data = Vec::new();
+  data.extend(b"#!AER-DAT2.0\r\n# This is a raw AE data file\r\n# created by a test\r\n".iter());
+  for &(x, y, p, t) in [(0, 0, true, 100u32), (127, 5, false, 250), (64, 127, true, 1100)].iter() {
+    for &word in [dvs_address(x, y, p), t].iter() {
+      data.extend([(word >> 24) as u8, (word >> 16) as u8, (word >> 8) as u8, word as u8].iter());
+    }
+  }
+
+  let path = write_file("neural_test_aer.aedat", &data);
+  let events = open_aedat(&path).unwrap();
+  fs::remove_file(&path).ok();
+
+  assert_eq!(events, vec![
+    event(0, 0, true, 100),
+    event(127, 5, false, 250),
+    event(64, 127, true, 1100),
+  ]);
+
+  // version 1.0 has 16-bit addresses
+  let mut data = Vec::new();
+  data.extend(b"#!AER-DAT1.0\r\n".iter());
+  let address = dvs_address(10, 20, false);
+  data.extend([(address >> 8) as u8, address as u8, 0, 0, 1, 0].iter());
+  assert_eq!(read_aedat(&data[..]).unwrap(), vec![event(10, 20, false, 256)]);
+
+  assert!(read_aedat(&b"#!AER-DAT3.1\r\n"[..]).is_err());
+  assert!(read_aedat(&data[..data.len() - 1]).is_err());
+}
+
+#[test]
+fn test_aer_stream() {
+  let mut stream = AerStream::new(AerConfig{
+    width: 4,
+    height: 2,
+    tick_us: 1000.0,
+    ..Default::default()
+  }, vec![
+    event(1, 1, true, 6500),
+    event(1, 0, false, 5000),
+    event(2, 1, false, 5999),
+    event(9, 0, true, 6000), // off the sensor
+    event(3, 1, true, 12000),
+  ]);
+  assert_eq!(stream.channels(), 16);
+
+  // ticks count from the first event, windows from their start
+  assert_eq!(stream.next_window(2), vec![
+    Event{ tick: 0, channel: 1 },
+    Event{ tick: 0, channel: 6 },
+    Event{ tick: 1, channel: 8 + 5 },
+  ]);
+  assert!(stream.next_window(5).is_empty());
+  assert!(!stream.is_done());
+  assert_eq!(stream.next_window(5), vec![Event{ tick: 0, channel: 8 + 7 }]);
+  assert!(stream.is_done());
+  assert_eq!(stream.tick(), 12);
+
+  stream.rewind();
+  assert_eq!(stream.next_window(100).len(), 4);
+
+  // without split polarity both share a neuron
+  let stream = AerStream::new(AerConfig{
+    width: 4,
+    height: 2,
+    split_polarity: false,
+    ..Default::default()
+  }, Vec::new());
+  assert_eq!(stream.channels(), 8);
+  assert_eq!(stream.channel(&event(1, 1, true, 0)), Some(5));
+  assert_eq!(stream.channel(&event(1, 1, false, 0)), Some(5));
+  assert!(stream.is_done());
+}
+
+#[test]
+fn test_aer_network() {
+  let config = AerConfig{
+    width: 2,
+    height: 2,
+    ..Default::default()
+  };
+
+  let mut network = Network::<IzhikevichNeuron, STDPSynapse<ExpTrace>>::new(20);
+  for _ in 0..8 {
+    network.add_neuron(IzhikevichNeuron::new(0.5, IzhikevichConfig::fast_spiking()));
+  }
+
+  let mut stream = AerStream::new(config, vec![
+    event(0, 0, true, 0),
+    event(1, 1, false, 30000),
+    event(0, 1, true, 61000),
+  ]);
+
+  // stream a window at a time, each event a strong input pulse
+  let mut monitor = SpikeMonitor::new(&(0..8).collect::<Vec<usize>>());
+  let window = 10;
+  let mut outputs = vec![0.0; 8];
+  while !stream.is_done() {
+    let mut inputs = vec![0.0; window * 8];
+    fill(&stream.next_window(window), 200.0, 0, 8, &mut inputs);
+    for t in 0..window {
+      for o in outputs.iter_mut() {
+        *o = 0.0;
+      }
+      network.tick(1, &inputs[t * 8..(t + 1) * 8], &mut outputs);
+      monitor.observe(&outputs);
+    }
+  }
+
+  // every event fires its neuron within a few ticks of its timestamp
+  let events = monitor.events();
+  assert_eq!(events.iter().map(|e| e.channel).collect::<Vec<usize>>(), vec![4, 3, 6]);
+  for (e, &tick) in events.iter().zip([0, 30, 61].iter()) {
+    assert!(e.tick >= tick && e.tick < tick + 3);
+  }
+}
@@ -0,0 +1,153 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate rand;
+
+use std::default::Default;
+use rand::{Rng, SeedableRng, StdRng};
+
+use neural::Float;
+use neural::Network;
+use neural::bptt::{Trainer, BPTTConfig};
+use neural::lif::{LIFNeuron, LIFConfig};
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::traces::ExpTrace;
+
+fn lif_config() -> LIFConfig {
+  LIFConfig{
+    tau_m: 10.0,
+    refractory: 0.0,
+    adaptive: false,
+    ..Default::default()
+  }
+}
+
+fn static_synapse(weight: Float) -> STDPSynapse<ExpTrace> {
+  STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: weight,
+    min: -1000.0,
+    max: 1000.0,
+    ..Default::default()
+  })
+}
+
+// Output spike counts of a built network for the same input sequence.
+fn network_counts(network: &mut Network<LIFNeuron, STDPSynapse<ExpTrace>>, ids: &[Vec<usize>], current: Float, inputs: &[Vec<Float>]) -> Vec<Float> {
+  let count = network.get_neuron_count();
+  let outputs = &ids[ids.len() - 1];
+  let mut counts = vec![0.0; outputs.len()];
+
+  for x in inputs.iter() {
+    let mut inp = vec![0.0; count];
+    let mut oup = vec![0.0; count];
+    for (i, &id) in ids[0].iter().enumerate() {
+      inp[id] = x[i] * current;
+    }
+    network.tick(1, &inp, &mut oup);
+    for (j, &id) in outputs.iter().enumerate() {
+      if oup[id] > 0.0 {
+        counts[j] += 1.0;
+      }
+    }
+  }
+  counts
+}
+
+// Two input channels that each either fire a burst or stay silent; the
+// label is their XOR.
+fn xor_samples() -> Vec<(Vec<Vec<Float>>, usize)> {
+  let mut samples = Vec::new();
+  for &(a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)].iter() {
+    let inputs = (0..30).map(|t| {
+      let on = t >= 2 && t < 12 && t % 2 == 0;
+      let mut x = vec![0.0; 4];
+      x[0] = if on && a == 1 { 1.0 } else { 0.0 };
+      x[1] = if on && a == 0 { 1.0 } else { 0.0 };
+      x[2] = if on && b == 1 { 1.0 } else { 0.0 };
+      x[3] = if on && b == 0 { 1.0 } else { 0.0 };
+      x
+    }).collect();
+    samples.push((inputs, a ^ b));
+  }
+  samples
+}
+
+// The spike counts of the trainer and of the network it builds agree.
+fn check_matches_network(neuron: LIFConfig) {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+
+  let mut trainer = Trainer::new(BPTTConfig{
+    weight_scale: 40.0,
+    ..Default::default()
+  }, neuron, 0.5, 10);
+  trainer.add_layer(20, true);
+  trainer.add_layer(5, false);
+
+  let mut network = Network::new(20);
+  network.toggle_learning(false);
+  let ids = trainer.build(&mut network, static_synapse).unwrap();
+  assert_eq!(ids.iter().map(|l| l.len()).collect::<Vec<usize>>(), vec![10, 20, 5]);
+
+  let inputs: Vec<Vec<Float>> = (0..100).map(|_| {
+    (0..10).map(|_| if rng.gen::<f64>() < 0.2 { 1.0 } else { 0.0 }).collect()
+  }).collect();
+
+  let expected = trainer.spike_counts(&inputs);
+  let current = trainer.input_current();
+  assert!(expected.iter().any(|&c| c > 0.0));
+  let counts = network_counts(&mut network, &ids, current, &inputs);
+  assert_eq!(counts, expected);
+}
+
+#[test]
+fn test_bptt_matches_network() {
+  check_matches_network(lif_config());
+}
+
+#[test]
+fn test_bptt_disables_refractory() {
+  // the trainer has no refractory period or adaptation, so the network it
+  // builds must not either
+  check_matches_network(LIFConfig{
+    tau_m: 10.0,
+    refractory: 3.0,
+    adaptive: true,
+    theta_plus: 5.0,
+    ..Default::default()
+  });
+}
+
+#[test]
+fn test_bptt_xor() {
+  let samples = xor_samples();
+
+  let mut trainer = Trainer::new(BPTTConfig{
+    learning_rate: 0.05,
+    ..Default::default()
+  }, lif_config(), 0.5, 4);
+  trainer.add_layer(16, false);
+  trainer.add_layer(2, false);
+
+  let first = trainer.train(&samples);
+  let mut loss = first;
+  for _ in 0..500 {
+    loss = trainer.train(&samples);
+  }
+  assert!(loss < first);
+
+  for &(ref inputs, label) in samples.iter() {
+    assert_eq!(trainer.predict(inputs), label);
+  }
+
+  // and the trained weights classify the same in a network
+  let mut network = Network::new(20);
+  network.toggle_learning(false);
+  let ids = trainer.build(&mut network, static_synapse).unwrap();
+  let current = trainer.input_current();
+  for &(ref inputs, label) in samples.iter() {
+    let counts = network_counts(&mut network, &ids, current, inputs);
+    assert!(counts[label] > counts[1 - label]);
+  }
+}
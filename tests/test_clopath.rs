@@ -0,0 +1,123 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::Synapse;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::clopath::{ClopathSynapse, ClopathConfig};
+use neural::traces::ExpTrace;
+
+#[test]
+fn test_clopath_ltd() {
+  let mut synapse = ClopathSynapse::<ExpTrace>::new(Default::default());
+  let mut now = 1.0;
+
+  // resting just above the depression threshold
+  while now < 50.0 {
+    synapse.post_voltage(-65.0, now);
+    now = now + 1.0;
+  }
+
+  let delta = synapse.pre_recv(now);
+  assert!(delta < 0.0);
+  assert!(synapse.weight() < 1.0);
+
+  // the presynaptic trace alone does not potentiate
+  let weight = synapse.weight();
+  while now < 100.0 {
+    synapse.post_voltage(-65.0, now);
+    now = now + 1.0;
+  }
+  assert_eq!(synapse.weight(), weight);
+}
+
+#[test]
+fn test_clopath_ltd_homeostasis() {
+  let depression = |ticks: usize, v: Float| {
+    let mut synapse = ClopathSynapse::<ExpTrace>::new(Default::default());
+    for t in 0..ticks {
+      synapse.post_voltage(v, t as Float);
+    }
+    -synapse.pre_recv(ticks as Float)
+  };
+
+  // depolarisation below `u_ref` on average weakens depression over time
+  // and depolarisation above it strengthens it
+  assert!(depression(5000, -65.0) < 0.6 * depression(50, -65.0));
+  assert!(depression(5000, -60.0) > 1.5 * depression(50, -60.0));
+}
+
+#[test]
+fn test_clopath_ltp() {
+  let mut synapse = ClopathSynapse::<ExpTrace>::new(Default::default());
+  let mut now = 1.0;
+
+  while now < 50.0 {
+    synapse.post_voltage(-65.0, now);
+    now = now + 1.0;
+  }
+
+  synapse.pre_recv(now);
+
+  // depolarized above `theta_pos` shortly after the presynaptic spike
+  while now < 60.0 {
+    synapse.post_voltage(-40.0, now);
+    now = now + 1.0;
+  }
+  assert!(synapse.weight() > 1.0);
+}
+
+#[test]
+fn test_clopath_no_ltp_without_pre() {
+  let mut synapse = ClopathSynapse::<ExpTrace>::new(Default::default());
+  let mut now = 1.0;
+
+  while now < 60.0 {
+    synapse.post_voltage(-40.0, now);
+    now = now + 1.0;
+  }
+  assert_eq!(synapse.weight(), 1.0);
+}
+
+// Fires the presynaptic neuron at 20Hz, optionally followed by a burst of
+// the slightly depolarized postsynaptic neuron, and returns the final weight.
+fn pairing(post: bool) -> Float {
+  let mut network = Network::new(20);
+
+  let neuron = IzhikevichNeuron::new(0.5, Default::default());
+  let a = network.add_neuron(neuron);
+  let b = network.add_neuron(neuron);
+
+  let synapse = ClopathSynapse::<ExpTrace>::new(ClopathConfig{
+    weight: 1.0,
+    ..Default::default()
+  });
+  network.add_synapse(synapse, a, b).unwrap();
+
+  for t in 0..1000 {
+    let mut inp: [Float; 2] = [0.0, 3.0];
+    let mut oup: [Float; 2] = [0.0, 0.0];
+    if t % 50 == 10 {
+      inp[a] = 1000.0;
+    }
+    if post && t % 50 >= 12 && t % 50 < 16 {
+      inp[b] += 60.0;
+    }
+    network.tick(1, &mut inp, &mut oup);
+  }
+
+  let mut weights: [Float; 1] = [0.0];
+  network.dump_weights(&mut weights);
+  weights[0]
+}
+
+#[test]
+fn test_clopath_network() {
+  assert!(pairing(true) > 1.0);
+  assert!(pairing(false) < 1.0);
+}
@@ -0,0 +1,103 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Neuron;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::conductance::{ConductanceNeuron, ConductanceConfig};
+
+// a neuron settled at its resting potential
+fn resting() -> IzhikevichNeuron {
+  let mut neuron = IzhikevichNeuron::new(0.5, Default::default());
+  for _ in 0..1000 {
+    neuron.tick(1.0);
+  }
+  neuron
+}
+
+// spikes evoked by a train of excitatory and inhibitory conductances
+fn spikes(config: ConductanceConfig, exc: Float, inh: Float) -> usize {
+  let mut neuron = ConductanceNeuron::new(config, resting());
+  let mut spikes = 0;
+  for t in 0..500 {
+    if t % 5 == 0 {
+      neuron.recv_at(exc, 0.0);
+      neuron.recv_at(-inh, 0.0);
+    }
+    neuron.tick(1.0);
+    if neuron.threshold() > 0.0 {
+      neuron.reset();
+      spikes += 1;
+    }
+  }
+  spikes
+}
+
+#[test]
+fn test_conductance_driving_force() {
+  let rest = resting().v;
+
+  // no current flows at the reversal potential
+  let mut neuron = resting();
+  neuron.recv_conductance(0.5, rest);
+  neuron.tick(1.0);
+  assert!((neuron.v - rest).abs() < 1e-3);
+
+  // and it flows towards the reversal potential otherwise
+  let mut neuron = resting();
+  neuron.recv_conductance(0.5, 0.0);
+  neuron.tick(1.0);
+  assert!(neuron.v > rest + 1.0);
+
+  let mut neuron = resting();
+  neuron.recv_conductance(0.5, -80.0);
+  neuron.tick(1.0);
+  assert!(neuron.v < rest - 1.0);
+}
+
+#[test]
+fn test_conductance_reversal_bound() {
+  // a strong inhibitory conductance never pushes past its reversal
+  let mut neuron = ConductanceNeuron::new(Default::default(), resting());
+  neuron.recv_at(-50.0, 0.0);
+  for _ in 0..20 {
+    neuron.tick(1.0);
+    assert!(neuron.voltage() > -80.0);
+  }
+
+  // whereas a hyperpolarizing current does
+  let mut neuron = resting();
+  neuron.recv(-100.0);
+  neuron.tick(1.0);
+  assert!(neuron.voltage() < -80.0);
+}
+
+#[test]
+fn test_conductance_decay() {
+  let mut neuron = ConductanceNeuron::new(Default::default(), resting());
+  neuron.recv_at(1.0, 0.0);
+  neuron.recv_at(-1.0, 0.0);
+  neuron.tick(1.0);
+  assert!((neuron.g_exc() - (-1.0 / 5.0 as Float).exp()).abs() < 1e-5);
+  assert!((neuron.g_inh() - (-1.0 / 10.0 as Float).exp()).abs() < 1e-5);
+}
+
+#[test]
+fn test_conductance_shunting() {
+  // inhibition reversing at rest leaves the resting neuron alone but
+  // divides down its excitatory drive
+  let config = ConductanceConfig{
+    e_inh: resting().v,
+    ..Default::default()
+  };
+  assert_eq!(spikes(config, 0.0, 1.0), 0);
+
+  let excited = spikes(config, 0.1, 0.0);
+  let shunted = spikes(config, 0.1, 0.5);
+  assert!(excited > 0);
+  assert!(shunted < excited / 2);
+}
@@ -0,0 +1,195 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::Synapse;
+use neural::network::NeuralError;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::vogels::{VogelsSynapse, VogelsConfig};
+use neural::traces::ExpTrace;
+use neural::connectivity::{Connection, SparseMatrix};
+use neural::export::{write_connections_csv, read_connections_csv};
+
+type SynapseType = STDPSynapse<ExpTrace>;
+
+fn synapse(weight: Float, delay: Float) -> SynapseType {
+  STDPSynapse::new(STDPConfig{
+    weight: weight,
+    min: -10.0,
+    max: 10.0,
+    delay: delay,
+    ..Default::default()
+  })
+}
+
+fn network() -> Network<IzhikevichNeuron, SynapseType> {
+  let mut network = Network::new(20);
+  for _ in 0..4 {
+    network.add_neuron(Default::default());
+  }
+  network.add_synapse(synapse(1.0, 1.0), 0, 2).unwrap();
+  network.add_synapse(synapse(-2.0, 3.0), 1, 2).unwrap();
+  network.add_synapse(synapse(0.5, 2.0), 0, 3).unwrap();
+  network.add_synapse(synapse(0.25, 1.0), 0, 3).unwrap();
+  network
+}
+
+fn connection(pre: usize, post: usize, weight: Float, delay: Float) -> Connection {
+  Connection{
+    pre: pre,
+    post: post,
+    weight: weight,
+    delay: delay,
+  }
+}
+
+#[test]
+fn test_connectivity_set_weight() {
+  let mut s = synapse(1.0, 1.0);
+  assert_eq!(s.set_weight(3.0), 2.0);
+  assert_eq!(s.weight(), 3.0);
+
+  // clamped to the bounds
+  s.set_weight(50.0);
+  assert_eq!(s.weight(), 10.0);
+
+  // inhibitory synapses keep their sign
+  let mut s = VogelsSynapse::<ExpTrace>::new(VogelsConfig{
+    max: 5.0,
+    ..Default::default()
+  });
+  s.set_weight(-2.0);
+  assert_eq!(s.weight(), -2.0);
+  s.set_weight(1.0);
+  assert_eq!(s.weight(), 0.0);
+}
+
+#[test]
+fn test_connectivity_connections() {
+  let mut network = network();
+  let connections = network.connections();
+  assert_eq!(connections, vec![
+    connection(0, 2, 1.0, 1.0),
+    connection(1, 2, -2.0, 3.0),
+    connection(0, 3, 0.5, 2.0),
+    connection(0, 3, 0.25, 1.0),
+  ]);
+
+  // rebuild an identical network from them
+  let mut copy = Network::<IzhikevichNeuron, SynapseType>::new(20);
+  for _ in 0..4 {
+    copy.add_neuron(Default::default());
+  }
+  let ids = copy.add_connections(&connections, |c| synapse(c.weight, c.delay)).unwrap();
+  assert_eq!(ids, vec![0, 1, 2, 3]);
+  assert_eq!(copy.connections(), connections);
+
+  // and through a file format
+  let mut buffer = Vec::new();
+  write_connections_csv(&mut buffer, &connections).unwrap();
+  assert!(String::from_utf8(buffer.clone()).unwrap().starts_with("pre,post,weight,delay\n0,2,1,1\n1,2,-2,3\n"));
+  assert_eq!(read_connections_csv(&buffer[..]).unwrap(), connections);
+
+  // weights load onto existing synapses, delays stay
+  network.set_connection_weights(&[connection(1, 2, 4.0, 9.0), connection(0, 3, 0.0, 1.0)]).unwrap();
+  let weights: Vec<Float> = network.connections().iter().map(|c| c.weight).collect();
+  assert_eq!(weights, vec![1.0, 4.0, 0.0, 0.0]);
+  assert_eq!(network.connections()[1].delay, 3.0);
+
+  match network.set_connection_weights(&[connection(0, 2, 5.0, 1.0), connection(2, 0, 1.0, 1.0)]) {
+    Err(NeuralError::MissingSynapse) => {},
+    _ => panic!("expected a missing synapse"),
+  }
+  assert_eq!(network.connections()[0].weight, 1.0);
+
+  assert!(copy.add_connections(&[connection(0, 7, 1.0, 1.0)], |c| synapse(c.weight, c.delay)).is_err());
+  assert_eq!(copy.get_synapse_count(), 4);
+}
+
+#[test]
+fn test_connectivity_matrix() {
+  let mut network = network();
+  let pre = [0, 1];
+  let post = [2, 3];
+
+  // parallel synapses sum
+  assert_eq!(network.weight_matrix(&pre, &post), vec![1.0, 0.75, -2.0, 0.0]);
+  assert_eq!(network.weight_matrix(&[1], &[3, 2]), vec![0.0, -2.0]);
+
+  let sparse = network.sparse_weight_matrix(&pre, &post);
+  assert_eq!(sparse, SparseMatrix{
+    rows: 2,
+    cols: 2,
+    indptr: vec![0, 2, 3],
+    indices: vec![0, 1, 0],
+    data: vec![1.0, 0.75, -2.0],
+  });
+  assert_eq!(sparse.to_dense(), network.weight_matrix(&pre, &post));
+  assert_eq!(SparseMatrix::from_dense(2, 2, &[1.0, 0.75, -2.0, 0.0]), sparse);
+
+  // parallel synapses share their entry, so matrices round trip
+  let weights = network.weight_matrix(&pre, &post);
+  network.set_weight_matrix(&pre, &post, &weights).unwrap();
+  assert_eq!(network.weight_matrix(&pre, &post), weights);
+  let sparse = network.sparse_weight_matrix(&pre, &post);
+  network.set_sparse_weight_matrix(&pre, &post, &sparse).unwrap();
+  assert_eq!(network.sparse_weight_matrix(&pre, &post), sparse);
+
+  network.set_weight_matrix(&pre, &post, &[2.0, 0.5, 3.0, 0.0]).unwrap();
+  assert_eq!(network.weight_matrix(&pre, &post), vec![2.0, 0.5, 3.0, 0.0]);
+
+  // a weight for an unconnected pair changes nothing
+  assert!(network.set_weight_matrix(&pre, &post, &[0.0, 0.0, 0.0, 1.0]).is_err());
+  assert_eq!(network.weight_matrix(&pre, &post), vec![2.0, 0.5, 3.0, 0.0]);
+
+  let sparse = SparseMatrix::from_dense(2, 2, &[-1.0, 0.0, 0.0, 0.0]);
+  network.set_sparse_weight_matrix(&pre, &post, &sparse).unwrap();
+  assert_eq!(network.weight_matrix(&pre, &post), vec![-1.0, 0.5, 3.0, 0.0]);
+  let sparse = SparseMatrix::from_dense(2, 2, &[0.0, 0.0, 0.0, 1.0]);
+  assert!(network.set_sparse_weight_matrix(&pre, &post, &sparse).is_err());
+
+  // matrices of the wrong shape are refused rather than indexed
+  let shape_error = |result: Result<(), NeuralError>| match result {
+    Err(NeuralError::InvalidShape) => true,
+    _ => false,
+  };
+  assert!(shape_error(network.set_weight_matrix(&pre, &post, &[1.0, 1.0, 1.0])));
+  assert!(shape_error(network.set_weight_matrix(&pre, &post, &[1.0; 6])));
+  let sparse = SparseMatrix::from_dense(2, 2, &[-1.0, 0.0, 0.0, 0.0]);
+  assert!(shape_error(network.set_sparse_weight_matrix(&pre, &[2], &sparse)));
+  assert!(shape_error(network.set_sparse_weight_matrix(&[0, 1, 2], &post, &sparse)));
+  let mut broken = sparse.clone();
+  broken.indices[0] = 2;
+  assert!(!broken.is_valid());
+  assert!(shape_error(network.set_sparse_weight_matrix(&pre, &post, &broken)));
+  let mut broken = sparse.clone();
+  broken.indptr = vec![0, 2, 1];
+  assert!(shape_error(network.set_sparse_weight_matrix(&pre, &post, &broken)));
+  let mut broken = sparse.clone();
+  broken.data.push(1.0);
+  assert!(shape_error(network.set_sparse_weight_matrix(&pre, &post, &broken)));
+  assert!(sparse.is_valid());
+  assert_eq!(network.weight_matrix(&pre, &post), vec![-1.0, 0.5, 3.0, 0.0]);
+}
+
+#[test]
+fn test_connectivity_dump_weights() {
+  let mut network = network();
+  network.remove_synapse(1);
+
+  // ids past the end are skipped rather than panicking
+  let mut weights = vec![0.0; network.get_synapse_count()];
+  network.dump_weights(&mut weights);
+  assert_eq!(weights, vec![1.0, 0.0, 0.5]);
+
+  network.load_weights(&[3.0, 7.0, -1.0, 2.0]);
+  let mut weights = vec![0.0; 4];
+  network.dump_weights(&mut weights);
+  assert_eq!(weights, vec![3.0, 0.0, -1.0, 2.0]);
+}
@@ -0,0 +1,267 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate rand;
+
+use std::default::Default;
+use std::env;
+use std::fs::{self, File};
+use rand::{Rng, SeedableRng, StdRng};
+
+use neural::Float;
+use neural::Network;
+use neural::Neuron;
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::traces::ExpTrace;
+use neural::export::{Array, write_npy, open_npy};
+use neural::conversion::{Converter, ConversionConfig, Normalization, IFNeuron, Layer};
+use neural::decoding::decoder::argmax;
+
+type SynapseType = STDPSynapse<ExpTrace>;
+
+fn static_synapse(weight: Float) -> SynapseType {
+  STDPSynapse::new(STDPConfig{
+    weight: weight,
+    min: -1000.0,
+    max: 1000.0,
+    delay: 1.0,
+    ..Default::default()
+  })
+}
+
+fn array(shape: &[usize], data: &[Float]) -> Array {
+  Array{
+    shape: shape.to_vec(),
+    data: data.iter().map(|&x| x as f64).collect(),
+  }
+}
+
+// noisy copies of a random prototype per class, in [0, 1]
+fn dataset(rng: &mut StdRng, prototypes: &[Vec<Float>], count: usize) -> Vec<(Vec<Float>, usize)> {
+  (0..count).map(|_| {
+    let label = rng.gen_range::<usize>(0, prototypes.len());
+    let x = prototypes[label].iter().map(|&p| {
+      let v = p + 0.6 * (rng.gen::<Float>() - 0.5);
+      if v < 0.0 { 0.0 } else if v > 1.0 { 1.0 } else { v }
+    }).collect();
+    (x, label)
+  }).collect()
+}
+
+// A 2-layer ReLU network trained by plain SGD on softmax cross-entropy,
+// standing in for one trained elsewhere. Weights are (outputs, inputs).
+struct Mlp {
+  sizes: [usize; 3],
+  weights: [Vec<Float>; 2],
+  biases: [Vec<Float>; 2],
+}
+
+impl Mlp {
+  fn new(rng: &mut StdRng, sizes: [usize; 3]) -> Mlp {
+    let mut init = |inputs: usize, outputs: usize| -> Vec<Float> {
+      let bound = 1.0 / (inputs as Float).sqrt();
+      (0..inputs * outputs).map(|_| (2.0 * rng.gen::<Float>() - 1.0) * bound).collect()
+    };
+    let weights = [init(sizes[0], sizes[1]), init(sizes[1], sizes[2])];
+    Mlp{
+      sizes: sizes,
+      weights: weights,
+      biases: [vec![0.0; sizes[1]], vec![0.0; sizes[2]]],
+    }
+  }
+
+  fn forward(&self, x: &[Float]) -> (Vec<Float>, Vec<Float>) {
+    let h: Vec<Float> = (0..self.sizes[1]).map(|j| {
+      let mut a = self.biases[0][j];
+      for i in 0..self.sizes[0] {
+        a += self.weights[0][j * self.sizes[0] + i] * x[i];
+      }
+      if a > 0.0 { a } else { 0.0 }
+    }).collect();
+    let y = (0..self.sizes[2]).map(|k| {
+      let mut a = self.biases[1][k];
+      for j in 0..self.sizes[1] {
+        a += self.weights[1][k * self.sizes[1] + j] * h[j];
+      }
+      a
+    }).collect();
+    (h, y)
+  }
+
+  fn train(&mut self, samples: &[(Vec<Float>, usize)], epochs: usize, rate: Float) {
+    for _ in 0..epochs {
+      for &(ref x, label) in samples.iter() {
+        let (h, y) = self.forward(x);
+        let max = y.iter().fold(y[0], |m, &v| if v > m { v } else { m });
+        let mut p: Vec<Float> = y.iter().map(|&v| (v - max).exp()).collect();
+        let mut total = 0.0;
+        for v in p.iter() {
+          total += *v;
+        }
+        for v in p.iter_mut() {
+          *v /= total;
+        }
+        p[label] -= 1.0;
+
+        for j in 0..self.sizes[1] {
+          if h[j] <= 0.0 {
+            continue;
+          }
+          let mut dh = 0.0;
+          for k in 0..self.sizes[2] {
+            dh += p[k] * self.weights[1][k * self.sizes[1] + j];
+          }
+          for i in 0..self.sizes[0] {
+            self.weights[0][j * self.sizes[0] + i] -= rate * dh * x[i];
+          }
+          self.biases[0][j] -= rate * dh;
+        }
+        for k in 0..self.sizes[2] {
+          for j in 0..self.sizes[1] {
+            self.weights[1][k * self.sizes[1] + j] -= rate * p[k] * h[j];
+          }
+          self.biases[1][k] -= rate * p[k];
+        }
+      }
+    }
+  }
+
+  fn accuracy(&self, samples: &[(Vec<Float>, usize)]) -> Float {
+    let correct = samples.iter().filter(|&&(ref x, label)| argmax(&self.forward(x).1) == Some(label)).count();
+    correct as Float / samples.len() as Float
+  }
+}
+
+#[test]
+fn test_conversion_if_neuron() {
+  // the rate follows the input, with reset by subtraction keeping the
+  // remainder
+  let mut neuron = IFNeuron::new(1.0);
+  let mut count = 0;
+  for _ in 0..100 {
+    neuron.recv(0.37);
+    neuron.tick(1.0);
+    if neuron.threshold() > 0.0 {
+      neuron.reset();
+      count += 1;
+    }
+  }
+  assert_eq!(count, 37);
+  assert!(neuron.v >= 0.0 && neuron.v < 1.0);
+
+  neuron.clear();
+  assert_eq!(neuron.v, 0.0);
+}
+
+#[test]
+fn test_conversion_dense() {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+
+  let prototypes: Vec<Vec<Float>> = (0..4).map(|_| (0..10).map(|_| rng.gen::<Float>()).collect()).collect();
+  let train = dataset(&mut rng, &prototypes, 400);
+  let test = dataset(&mut rng, &prototypes, 100);
+
+  let mut mlp = Mlp::new(&mut rng, [10, 20, 4]);
+  mlp.train(&train, 30, 0.05);
+  let ann_accuracy = mlp.accuracy(&test);
+  assert!(ann_accuracy > 0.9);
+
+  // hand the weights over as .npy files
+  let dir = env::temp_dir();
+  let names = ["neural_test_w0.npy", "neural_test_b0.npy", "neural_test_w1.npy", "neural_test_b1.npy"];
+  write_npy(File::create(dir.join(names[0])).unwrap(), &[20, 10], &mlp.weights[0]).unwrap();
+  write_npy(File::create(dir.join(names[1])).unwrap(), &[20], &mlp.biases[0]).unwrap();
+  write_npy(File::create(dir.join(names[2])).unwrap(), &[4, 20], &mlp.weights[1]).unwrap();
+  write_npy(File::create(dir.join(names[3])).unwrap(), &[4], &mlp.biases[1]).unwrap();
+
+  let mut converter = Converter::new(Default::default(), &[10]);
+  let arrays: Vec<Array> = names.iter().map(|name| open_npy(dir.join(name)).unwrap()).collect();
+  converter.add_dense(&arrays[0], Some(&arrays[1])).unwrap();
+  converter.add_dense(&arrays[2], Some(&arrays[3])).unwrap();
+  for name in names.iter() {
+    fs::remove_file(dir.join(name)).ok();
+  }
+
+  // mismatched shapes are refused
+  assert!(converter.add_dense(&arrays[2], None).is_err());
+  assert!(converter.add_dense(&array(&[3, 4], &[0.0; 12]), Some(&array(&[2], &[0.0; 2]))).is_err());
+
+  // normalizing leaves the rate-based predictions alone
+  let predictions: Vec<usize> = test.iter().map(|&(ref x, _)| converter.predict(x)).collect();
+  let samples: Vec<Vec<Float>> = train.iter().map(|&(ref x, _)| x.clone()).collect();
+  converter.normalize(&samples);
+  for (&(ref x, _), &p) in test.iter().zip(predictions.iter()) {
+    assert_eq!(converter.predict(x), p);
+    assert_eq!(argmax(&mlp.forward(x).1), Some(p));
+  }
+
+  let mut network = Network::new(20);
+  network.toggle_learning(false);
+  let ids = converter.build(&mut network, static_synapse).unwrap();
+  assert_eq!(ids.iter().map(|l| l.len()).collect::<Vec<usize>>(), vec![10, 20, 4]);
+
+  let correct = test.iter().filter(|&&(ref x, label)| {
+    argmax(&converter.run(&mut network, &ids, x, 100)) == Some(label)
+  }).count();
+  let snn_accuracy = correct as Float / test.len() as Float;
+  assert!(snn_accuracy >= ann_accuracy - 0.05);
+}
+
+#[test]
+fn test_conversion_conv2d() {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+
+  let mut converter = Converter::new(ConversionConfig{
+    normalization: Normalization::Max,
+    ..Default::default()
+  }, &[1, 6, 6]);
+
+  // a vertical edge detector and a blur, then a dense readout
+  let kernels = [
+    -1.0, 0.0, 1.0, -1.0, 0.0, 1.0, -1.0, 0.0, 1.0,
+    0.1, 0.1, 0.1, 0.1, 0.2, 0.1, 0.1, 0.1, 0.1,
+  ];
+  converter.add_conv2d(&array(&[2, 1, 3, 3], &kernels), Some(&array(&[2], &[0.0, -0.1])), 1, 1).unwrap();
+  match converter.layers()[0] {
+    Layer::Conv2d{output, ..} => assert_eq!(output, [2, 6, 6]),
+    _ => panic!("expected a convolution"),
+  }
+  assert!(converter.add_conv2d(&array(&[1, 3, 3, 3], &[0.0; 27]), None, 1, 0).is_err());
+
+  let readout: Vec<Float> = (0..3 * 72).map(|_| rng.gen::<Float>() - 0.5).collect();
+  converter.add_dense(&array(&[3, 72], &readout), None).unwrap();
+
+  // a step edge at column 3 lights the edge detector along it, zero
+  // padding adds another at the right border
+  let mut edge = vec![0.0; 36];
+  for y in 0..6 {
+    for x in 3..6 {
+      edge[y * 6 + x] = 1.0;
+    }
+  }
+  let activations = converter.forward(&edge);
+  assert_eq!(activations[0][2 * 6 + 2], 3.0);
+  assert_eq!(activations[0][2 * 6 + 3], 3.0);
+  assert_eq!(activations[0][2 * 6 + 0], 0.0);
+  assert!((activations[0][36 + 2 * 6 + 4] - (1.0 - 0.1)).abs() < 1e-5);
+
+  let samples: Vec<Vec<Float>> = (0..50).map(|_| (0..36).map(|_| rng.gen::<Float>()).collect()).collect();
+  converter.normalize(&samples);
+
+  let mut network = Network::new(20);
+  network.toggle_learning(false);
+  let ids = converter.build(&mut network, static_synapse).unwrap();
+  assert_eq!(ids.iter().map(|l| l.len()).collect::<Vec<usize>>(), vec![36, 72, 3]);
+
+  // spike rates of the converted network pick the same class
+  let mut agree = 0;
+  for sample in samples.iter().take(20) {
+    if argmax(&converter.run(&mut network, &ids, sample, 200)) == Some(converter.predict(sample)) {
+      agree += 1;
+    }
+  }
+  assert!(agree >= 16);
+}
@@ -0,0 +1,133 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::f32::consts::PI;
+
+use neural::Float;
+use neural::Network;
+use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
+use neural::stdp::STDPSynapse;
+use neural::traces::ExpTrace;
+use neural::encoding::Event;
+use neural::decoding::{Decoder, Classifier, SpikeMonitor, SpikeCountClassifier, FirstSpikeClassifier,
+  PopulationVectorDecoder, RateDecoder};
+
+fn event(tick: usize, channel: usize) -> Event {
+  Event{
+    tick: tick,
+    channel: channel,
+  }
+}
+
+#[test]
+fn test_decoding_monitor() {
+  let mut network = Network::<IzhikevichNeuron, STDPSynapse<ExpTrace>>::new(20);
+  for _ in 0..4 {
+    network.add_neuron(IzhikevichNeuron::new(0.5, IzhikevichConfig::fast_spiking()));
+  }
+
+  // only neurons 1 and 3 are driven, and only 2 and 3 are monitored
+  let mut monitor = SpikeMonitor::new(&[2, 3]);
+  let inputs = [0.0, 20.0, 0.0, 20.0];
+  let mut outputs = [0.0; 4];
+  for _ in 0..100 {
+    for o in outputs.iter_mut() {
+      *o = 0.0;
+    }
+    network.tick(1, &inputs, &mut outputs);
+    monitor.observe(&outputs);
+  }
+
+  assert_eq!(monitor.ticks(), 100);
+  assert!(monitor.events().len() > 1);
+  assert!(monitor.events().iter().all(|e| e.channel == 1 && e.tick < 100));
+  assert!(monitor.events().windows(2).all(|w| w[0].tick < w[1].tick));
+
+  let events = monitor.take_events();
+  assert!(!events.is_empty());
+  assert_eq!(monitor.ticks(), 0);
+  assert!(monitor.events().is_empty());
+}
+
+#[test]
+fn test_decoding_spike_count() {
+  // channels 0 and 1 belong to class 0, 2 to class 1 and 3 to class 2
+  let classifier = SpikeCountClassifier::new(&[0, 0, 1, 2]);
+  let events = vec![event(0, 0), event(3, 0), event(5, 2), event(6, 2), event(9, 3)];
+  assert_eq!(classifier.decode(&events, 10), vec![1.0, 2.0, 1.0]);
+  assert_eq!(classifier.classify(&events, 10), Some(1));
+  assert_eq!(classifier.classify(&[], 10), None);
+
+  // channels are assigned the class they respond to most
+  let recordings = vec![
+    (vec![event(0, 0), event(1, 0), event(1, 2)], 1),
+    (vec![event(0, 1), event(1, 2), event(2, 2)], 0),
+    (vec![event(0, 1), event(4, 0)], 0),
+  ];
+  let classifier = SpikeCountClassifier::fit(4, 2, &recordings).unwrap();
+  assert_eq!(classifier.assignments(), &[1, 0, 0, 0]);
+  assert_eq!(classifier.decode(&[], 10).len(), 2);
+  assert_eq!(classifier.classify(&[event(0, 0), event(1, 0)], 10), Some(1));
+
+  // out of range channels are ignored and out of range labels rejected
+  let recordings = vec![(vec![event(0, 1), event(1, 9)], 1)];
+  let classifier = SpikeCountClassifier::fit(4, 2, &recordings).unwrap();
+  assert_eq!(classifier.assignments(), &[0, 1, 0, 0]);
+  assert!(SpikeCountClassifier::fit(4, 2, &[(vec![event(0, 1)], 2)]).is_err());
+}
+
+#[test]
+fn test_decoding_first_spike() {
+  let classifier = FirstSpikeClassifier::new(&[0, 1, 1, 2]);
+  let events = vec![event(4, 0), event(2, 3), event(7, 1), event(2, 1), event(2, 2)];
+  assert_eq!(classifier.decode(&events, 10), vec![4.0, 2.0, 2.0]);
+
+  // classes 1 and 2 tie on the first tick but class 1 has more spikes
+  assert_eq!(classifier.classify(&events, 10), Some(1));
+  assert_eq!(classifier.classify(&events[..2], 10), Some(2));
+  assert_eq!(classifier.classify(&[], 10), None);
+  assert_eq!(classifier.decode(&[], 10), vec![10.0, 10.0, 10.0]);
+}
+
+#[test]
+fn test_decoding_population_vector() {
+  let decoder = PopulationVectorDecoder::uniform(8);
+
+  // equal votes either side of 3pi/2 decode to it, across the wrap too
+  let events = vec![event(0, 5), event(1, 7), event(2, 6), event(3, 6)];
+  let decoded = decoder.decode(&events, 4);
+  assert!((decoded[0] - 1.5 * PI).abs() < 1e-4);
+
+  let events = vec![event(0, 7), event(1, 1)];
+  let decoded = decoder.decode(&events, 4);
+  assert!(decoded[0].abs() < 1e-4 || (decoded[0] - 2.0 * PI).abs() < 1e-4);
+
+  // opposite votes cancel
+  let decoded = decoder.decode(&[event(0, 0), event(0, 4)], 1);
+  assert!(decoded[1] < 1e-4);
+
+  let decoded = decoder.decode(&[event(0, 2), event(1, 2)], 4);
+  assert!((decoded[0] - 0.5 * PI).abs() < 1e-4);
+  assert!((decoded[1] - 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_decoding_rate() {
+  let decoder = RateDecoder::new(10.0, 2);
+
+  // a single spike decays from 1 / tau
+  let rates = decoder.rates(&[event(2, 1)], 5);
+  assert_eq!(rates.len(), 10);
+  assert_eq!(&rates[..4], &[0.0, 0.0, 0.0, 0.0]);
+  assert!((rates[5] - 0.1).abs() < 1e-6);
+  assert!((rates[9] - 0.1 * (-0.2 as Float).exp()).abs() < 1e-6);
+
+  // and with a long time constant a regular train settles at its rate
+  let decoder = RateDecoder::new(100.0, 2);
+  let events: Vec<Event> = (0..1000).filter(|t| t % 5 == 0).map(|t| event(t, 0)).collect();
+  let decoded = decoder.decode(&events, 1000);
+  assert!((decoded[0] - 0.2).abs() < 0.01);
+  assert_eq!(decoded[1], 0.0);
+}
@@ -0,0 +1,203 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
+use neural::stdp::STDPSynapse;
+use neural::traces::ExpTrace;
+use neural::encoding::{Encoder, Event, PoissonEncoder, PoissonConfig, LatencyEncoder, LatencyConfig,
+  RankOrderEncoder, PopulationEncoder, PopulationConfig, DeltaEncoder, DeltaConfig, fill, dense};
+
+fn counts(events: &[Event], channels: usize) -> Vec<usize> {
+  let mut counts = vec![0; channels];
+  for event in events.iter() {
+    counts[event.channel] += 1;
+  }
+  counts
+}
+
+#[test]
+fn test_encoding_poisson() {
+  let mut encoder = PoissonEncoder::new(PoissonConfig{
+    max_rate: 0.2,
+    ..Default::default()
+  }, 3);
+  assert_eq!(encoder.channels(), 3);
+
+  let events = encoder.encode(&[0.0, 0.5, 1.0], 10000);
+  let counts = counts(&events, 3);
+  assert_eq!(counts[0], 0);
+  assert!((counts[1] as Float / 10000.0 - 0.1).abs() < 0.01);
+  assert!((counts[2] as Float / 10000.0 - 0.2).abs() < 0.01);
+
+  // sorted by tick
+  assert!(events.windows(2).all(|w| w[0].tick <= w[1].tick));
+}
+
+#[test]
+fn test_encoding_latency() {
+  let mut encoder = LatencyEncoder::new(Default::default(), 4);
+  let events = encoder.encode(&[1.0, 0.0, 0.5, 0.75], 21);
+  assert_eq!(events, vec![
+    Event{ tick: 0, channel: 0 },
+    Event{ tick: 5, channel: 3 },
+    Event{ tick: 10, channel: 2 },
+  ]);
+
+  // the logarithmic code is also earlier for larger values, and values
+  // near the threshold fall out of the window
+  let mut encoder = LatencyEncoder::new(LatencyConfig{
+    threshold: 0.2,
+    linear: false,
+    ..Default::default()
+  }, 3);
+  let events = encoder.encode(&[1.0, 0.5, 0.21], 10);
+  assert_eq!(events.len(), 2);
+  assert_eq!(events[0].channel, 0);
+  assert_eq!(events[1].channel, 1);
+  assert!(events[0].tick < events[1].tick);
+}
+
+#[test]
+fn test_encoding_rank_order() {
+  let mut encoder = RankOrderEncoder::new(Default::default(), 5);
+  let events = encoder.encode(&[0.3, 0.9, 0.0, 0.3, 0.5], 10);
+  let channels: Vec<usize> = events.iter().map(|e| e.channel).collect();
+  let ticks: Vec<usize> = events.iter().map(|e| e.tick).collect();
+  assert_eq!(channels, vec![1, 4, 0, 3]);
+  assert_eq!(ticks, vec![0, 1, 2, 3]);
+
+  // ranks past the window are dropped
+  assert_eq!(encoder.encode(&[0.3, 0.9, 0.0, 0.3, 0.5], 2).len(), 2);
+}
+
+#[test]
+fn test_encoding_population() {
+  let mut encoder = PopulationEncoder::new(PopulationConfig{
+    fields: 6,
+    ..Default::default()
+  }, 2).unwrap();
+  assert_eq!(encoder.channels(), 12);
+
+  let events = encoder.encode(&[0.1, 0.6], 11);
+
+  // the field centred nearest each value fires first and its neighbours
+  // follow, distant fields stay silent
+  let first: Vec<&Event> = events.iter().filter(|e| e.channel < 6).collect();
+  assert_eq!(first[0].channel, 1);
+  assert!(first.iter().all(|e| e.channel < 4));
+
+  let second: Vec<&Event> = events.iter().filter(|e| e.channel >= 6).collect();
+  assert_eq!(second[0].channel, 6 + 3);
+  assert!(second.len() >= 2);
+  assert!(second.iter().all(|e| e.tick >= second[0].tick));
+}
+
+#[test]
+fn test_encoding_delta() {
+  let mut encoder = DeltaEncoder::new(DeltaConfig{
+    threshold: 0.1,
+  }, 1).unwrap();
+  assert_eq!(encoder.channels(), 2);
+
+  // a triangle wave up by 1 and back down
+  let signal: Vec<Float> = (0..201).map(|t| {
+    if t < 100 { t as Float * 0.01 } else { (200 - t) as Float * 0.01 }
+  }).collect();
+  let events = encoder.encode(&signal, 201);
+  let counts = counts(&events, 2);
+  assert!(counts[0] >= 9 && counts[0] <= 10);
+  assert!(counts[1] >= 9 && counts[1] <= 10);
+  assert!(events.iter().filter(|e| e.tick < 100).all(|e| e.channel == 0));
+
+  // the reference carries over, a held signal is silent
+  assert!(encoder.encode(&[0.0; 50], 50).is_empty());
+
+  // a step emits several spikes at once
+  let events = encoder.encode(&[0.55], 1);
+  assert_eq!(events.len(), 5);
+  assert!(events.iter().all(|e| e.tick == 0 && e.channel == 0));
+
+  // ticks past the end of the samples are not encoded
+  let events = encoder.encode(&[0.55, 0.75], 10);
+  assert_eq!(events.len(), 2);
+  assert!(events.iter().all(|e| e.tick == 1));
+}
+
+#[test]
+fn test_encoding_delta_threshold() {
+  for &threshold in [0.0, -0.1].iter() {
+    assert!(DeltaEncoder::new(DeltaConfig{
+      threshold: threshold,
+    }, 1).is_err());
+  }
+
+  // steps count every threshold crossed, however small
+  let mut encoder = DeltaEncoder::new(DeltaConfig{
+    threshold: 0.0005,
+  }, 1).unwrap();
+  let events = encoder.encode(&[0.0, 1.0, 0.0], 3);
+  assert_eq!(events.iter().filter(|e| e.channel == 0).count(), 2000);
+  assert_eq!(events.iter().filter(|e| e.channel == 1).count(), 2000);
+
+  let mut encoder = DeltaEncoder::new(DeltaConfig{
+    threshold: 0.001,
+  }, 1).unwrap();
+  assert_eq!(encoder.encode(&[0.0, 100.0], 2).len(), 100000);
+
+  // and terminate once the threshold is below the reference's precision
+  let mut encoder = DeltaEncoder::new(DeltaConfig{
+    threshold: 0.0005,
+  }, 1).unwrap();
+  assert!(encoder.encode(&[20000.0, 20000.0, 20000.01], 3).len() < 25);
+}
+
+#[test]
+fn test_encoding_population_fields() {
+  for &fields in [0, 1, 2].iter() {
+    assert!(PopulationEncoder::new(PopulationConfig{
+      fields: fields,
+      ..Default::default()
+    }, 1).is_err());
+  }
+}
+
+#[test]
+fn test_encoding_network() {
+  let events = vec![
+    Event{ tick: 0, channel: 1 },
+    Event{ tick: 2, channel: 0 },
+    Event{ tick: 2, channel: 0 },
+  ];
+  assert_eq!(dense(&events, 1.5, 2, 3), vec![0.0, 1.5, 0.0, 0.0, 3.0, 0.0]);
+
+  // channels map onto neurons after an offset, out of range events drop
+  let mut buffer = vec![0.0; 3 * 3];
+  fill(&events, 1.0, 1, 3, &mut buffer);
+  fill(&[Event{ tick: 5, channel: 0 }, Event{ tick: 0, channel: 2 }], 1.0, 1, 3, &mut buffer);
+  assert_eq!(buffer, vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+
+  // and drive a network directly
+  let mut network = Network::<IzhikevichNeuron, STDPSynapse<ExpTrace>>::new(20);
+  for _ in 0..10 {
+    network.add_neuron(IzhikevichNeuron::new(0.5, IzhikevichConfig::fast_spiking()));
+  }
+
+  let mut encoder = PoissonEncoder::new(PoissonConfig{
+    max_rate: 0.5,
+    ..Default::default()
+  }, 5);
+  let mut inputs = vec![0.0; 100 * 10];
+  let mut outputs = vec![0.0; 10];
+  let values = [1.0, 1.0, 1.0, 1.0, 1.0];
+  fill(&encoder.encode(&values, 100), 120.0, 5, 10, &mut inputs);
+  network.tick(100, &inputs, &mut outputs);
+
+  assert!(outputs[..5].iter().all(|&o| o == 0.0));
+  assert!(outputs[5..].iter().all(|&o| o > 0.0));
+}
@@ -0,0 +1,176 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::env;
+use std::io;
+use std::fs::{self, File};
+
+use neural::Float;
+use neural::Network;
+use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
+use neural::stdp::STDPSynapse;
+use neural::traces::ExpTrace;
+use neural::encoding::Event;
+use neural::decoding::SpikeMonitor;
+use neural::export::{write_spikes_csv, read_spikes_csv, write_states_csv, read_states_csv,
+  write_spikes_binary, read_spikes_binary, write_npy, write_npy_spikes, write_npy_states, read_npy,
+  Array, Npz, read_npz};
+
+// spikes of a few driven neurons, recorded by neuron id
+fn recorded_spikes() -> Vec<Event> {
+  let mut network = Network::<IzhikevichNeuron, STDPSynapse<ExpTrace>>::new(20);
+  for _ in 0..4 {
+    network.add_neuron(IzhikevichNeuron::new(0.5, IzhikevichConfig::fast_spiking()));
+  }
+
+  let mut monitor = SpikeMonitor::new(&[1, 3]);
+  let inputs = [0.0, 20.0, 0.0, 10.0];
+  let mut outputs = [0.0; 4];
+  for _ in 0..200 {
+    for o in outputs.iter_mut() {
+      *o = 0.0;
+    }
+    network.tick(1, &inputs, &mut outputs);
+    monitor.observe(&outputs);
+  }
+  monitor.neuron_events()
+}
+
+fn states() -> Vec<Vec<Float>> {
+  vec![vec![0.0, 1.5, -2.25], vec![1e-6, 3.0e8, 0.1]]
+}
+
+#[test]
+fn test_export_csv() {
+  let events = recorded_spikes();
+  assert!(events.iter().any(|e| e.channel == 1) && events.iter().any(|e| e.channel == 3));
+
+  let mut buffer = Vec::new();
+  write_spikes_csv(&mut buffer, &events[..2]).unwrap();
+  assert_eq!(String::from_utf8(buffer.clone()).unwrap(),
+    format!("t,i\n{},{}\n{},{}\n", events[0].tick, events[0].channel, events[1].tick, events[1].channel));
+
+  // round trip through a file
+  let path = env::temp_dir().join("neural_test_export_spikes.csv");
+  write_spikes_csv(File::create(&path).unwrap(), &events).unwrap();
+  assert_eq!(read_spikes_csv(File::open(&path).unwrap()).unwrap(), events);
+  fs::remove_file(&path).ok();
+
+  let mut buffer = Vec::new();
+  write_states_csv(&mut buffer, &states()).unwrap();
+  assert!(String::from_utf8(buffer.clone()).unwrap().starts_with("k,x0,x1,x2\n0,0,1.5,-2.25\n"));
+  assert_eq!(read_states_csv(&buffer[..]).unwrap(), states());
+
+  assert!(read_spikes_csv(&b"t,i\n1,2,3\n"[..]).is_err());
+  assert!(read_spikes_csv(&b"x,y\n1,2\n"[..]).is_err());
+}
+
+#[test]
+fn test_export_binary() {
+  let events = recorded_spikes();
+  let mut buffer = Vec::new();
+  write_spikes_binary(&mut buffer, &events).unwrap();
+  assert_eq!(buffer.len(), 16 + 8 * events.len());
+  assert_eq!(&buffer[..4], b"NSPK");
+  assert_eq!(read_spikes_binary(&buffer[..]).unwrap(), events);
+
+  assert!(read_spikes_binary(&buffer[..buffer.len() - 1]).is_err());
+  assert!(read_spikes_binary(&b"NOPE"[..]).is_err());
+}
+
+#[test]
+fn test_export_npy() {
+  let events = recorded_spikes();
+  let mut buffer = Vec::new();
+  write_npy_spikes(&mut buffer, &events).unwrap();
+
+  // header padded to a 64 byte boundary
+  assert_eq!(&buffer[..8], b"\x93NUMPY\x01\x00");
+  let len = buffer[8] as usize + 256 * buffer[9] as usize;
+  assert_eq!((10 + len) % 64, 0);
+  let header = String::from_utf8(buffer[10..10 + len].to_vec()).unwrap();
+  assert!(header.starts_with(&format!("{{'descr': '<u4', 'fortran_order': False, 'shape': ({}, 2), }}", events.len())));
+  assert!(header.ends_with(" \n"));
+
+  let array = read_npy(&buffer[..]).unwrap();
+  assert_eq!(array.shape, vec![events.len(), 2]);
+  assert_eq!(array.to_events().unwrap(), events);
+
+  let mut buffer = Vec::new();
+  write_npy_states(&mut buffer, &states()).unwrap();
+  assert_eq!(read_npy(&buffer[..]).unwrap().to_states().unwrap(), states());
+
+  let mut buffer = Vec::new();
+  write_npy(&mut buffer, &[4], &[1.0, 2.0, 3.0, 4.0]).unwrap();
+  assert_eq!(read_npy(&buffer[..]).unwrap(), Array{
+    shape: vec![4],
+    data: vec![1.0, 2.0, 3.0, 4.0],
+  });
+}
+
+#[test]
+fn test_export_npy_dtypes() {
+  fn npy(header: &str, body: &[u8]) -> Vec<u8> {
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.push(header.len() as u8);
+    bytes.push(0);
+    bytes.extend(header.as_bytes().iter());
+    bytes.extend(body.iter());
+    bytes
+  }
+
+  // signed integers as numpy writes them
+  let bytes = npy("{'descr': '<i2', 'fortran_order': False, 'shape': (3,), }\n", &[0xff, 0xff, 2, 0, 0, 0x80]);
+  assert_eq!(read_npy(&bytes[..]).unwrap().data, vec![-1.0, 2.0, -32768.0]);
+
+  // big-endian doubles
+  let bytes = npy("{'descr': '>f8', 'fortran_order': False, 'shape': (1,), }\n", &[0x3f, 0xf8, 0, 0, 0, 0, 0, 0]);
+  assert_eq!(read_npy(&bytes[..]).unwrap().data, vec![1.5]);
+
+  // column-major arrays come back row-major
+  let bytes = npy("{'descr': '|u1', 'fortran_order': True, 'shape': (2, 3), }\n", &[1, 4, 2, 5, 3, 6]);
+  let array = read_npy(&bytes[..]).unwrap();
+  assert_eq!(array.shape, vec![2, 3]);
+  assert_eq!(array.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+  let bytes = npy("{'descr': '<c8', 'fortran_order': False, 'shape': (1,), }\n", &[0; 8]);
+  assert!(read_npy(&bytes[..]).is_err());
+  let bytes = npy("{'descr': '<f4', 'fortran_order': False, 'shape': (2,), }\n", &[0; 4]);
+  assert!(read_npy(&bytes[..]).is_err());
+
+  // truncated and overflowing inputs are invalid data, not panics
+  let error = read_npy(&b"\x93NUMPY"[..]).unwrap_err();
+  assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+  let bytes = npy("{'descr': '<f4', 'fortran_order': False, 'shape': (4294967296, 4294967296, 2), }\n", &[0; 4]);
+  assert_eq!(read_npy(&bytes[..]).unwrap_err().kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_export_npz() {
+  let events = recorded_spikes();
+
+  let mut npz = Npz::new();
+  npz.add_spikes("spikes", &events);
+  npz.add_states("states", &states()).unwrap();
+  npz.add_array("weights", &[2, 2], &[0.5, -0.5, 1.0, 0.0]).unwrap();
+
+  let path = env::temp_dir().join("neural_test_export.npz");
+  npz.write(File::create(&path).unwrap()).unwrap();
+  let arrays = read_npz(File::open(&path).unwrap()).unwrap();
+  fs::remove_file(&path).ok();
+
+  let names: Vec<&str> = arrays.iter().map(|&(ref name, _)| &name[..]).collect();
+  assert_eq!(names, vec!["spikes", "states", "weights"]);
+  assert_eq!(arrays[0].1.to_events().unwrap(), events);
+  assert_eq!(arrays[1].1.to_states().unwrap(), states());
+  assert_eq!(arrays[2].1.shape, vec![2, 2]);
+  assert_eq!(arrays[2].1.to_floats(), vec![0.5, -0.5, 1.0, 0.0]);
+
+  // a corrupted entry fails its checksum
+  let mut bytes = Vec::new();
+  npz.write(&mut bytes).unwrap();
+  bytes[100] ^= 1;
+  assert!(read_npz(&bytes[..]).is_err());
+}
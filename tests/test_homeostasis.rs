@@ -0,0 +1,108 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate rand;
+
+use std::default::Default;
+use rand::{Rng, SeedableRng, StdRng};
+
+use neural::Float;
+use neural::Network;
+use neural::Synapse;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::homeostasis::HomeostasisConfig;
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::traces::ExpTrace;
+
+#[test]
+fn test_homeostasis_scale_bounds() {
+  let mut synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: 5.0,
+    min: 1.0,
+    max: 6.0,
+    ..Default::default()
+  });
+
+  synapse.scale(1.1);
+  assert_eq!(synapse.weight(), 5.5);
+  synapse.scale(2.0);
+  assert_eq!(synapse.weight(), 6.0);
+  synapse.scale(0.0);
+  assert_eq!(synapse.weight(), 1.0);
+}
+
+// Firing rate of a neuron driven by random inputs through fixed weights,
+// measured over the last quarter of the run.
+fn output_rate(weight: Float, homeostasis: bool) -> Float {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+  let mut network = Network::new(20);
+
+  let input_count = 20;
+  let ticks = 40000;
+  let target_rate = 0.01;
+
+  let neuron = IzhikevichNeuron::new(0.5, Default::default());
+  for _ in 0..input_count + 1 {
+    network.add_neuron(neuron);
+  }
+
+  let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: weight,
+    min: 0.0,
+    max: 20.0,
+    n_pos: 0.0,
+    n_neg: 0.0,
+    ..Default::default()
+  });
+  for i in 0..input_count {
+    network.add_synapse(synapse, i, input_count).unwrap();
+  }
+
+  if homeostasis {
+    network.add_homeostasis(&[input_count], HomeostasisConfig{
+      target_rate: target_rate,
+      ..Default::default()
+    }).unwrap();
+  }
+
+  let mut spikes = 0;
+  for t in 0..ticks {
+    let mut inp = vec![0.0; input_count + 1];
+    let mut oup = vec![0.0; input_count + 1];
+    for i in 0..input_count {
+      if rng.gen::<f64>() < 0.01 {
+        inp[i] = 100.0;
+      }
+    }
+
+    network.tick(1, &inp, &mut oup);
+    if t >= ticks * 3 / 4 && oup[input_count] > 0.0 {
+      spikes += 1;
+    }
+  }
+
+  spikes as Float / (ticks / 4) as Float
+}
+
+#[test]
+fn test_homeostasis_rate() {
+  // too strong and too weak inputs both settle near the target
+  let strong = output_rate(15.0, false);
+  let weak = output_rate(2.0, false);
+  assert!(strong > 0.02);
+  assert!(weak < 0.002);
+
+  let strong = output_rate(15.0, true);
+  let weak = output_rate(2.0, true);
+  assert!((strong - 0.01).abs() < 0.005);
+  assert!((weak - 0.01).abs() < 0.005);
+}
+
+#[test]
+fn test_homeostasis_missing_neuron() {
+  let mut network: Network<IzhikevichNeuron, STDPSynapse<ExpTrace>> = Network::new(20);
+  network.add_neuron(Default::default());
+  assert!(network.add_homeostasis(&[0, 1], Default::default()).is_err());
+}
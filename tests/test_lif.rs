@@ -0,0 +1,109 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::Neuron;
+use neural::lif::{LIFNeuron, LIFConfig};
+use neural::stdp::STDPSynapse;
+use neural::traces::ExpTrace;
+
+// ticks at which a neuron under constant input fires
+fn spike_times(neuron: &mut LIFNeuron, input: Float, ticks: usize) -> Vec<usize> {
+  let mut times = Vec::new();
+  for t in 0..ticks {
+    neuron.recv(input);
+    neuron.tick(1.0);
+    if neuron.threshold() > 0.0 {
+      neuron.reset();
+      times.push(t);
+    }
+  }
+  times
+}
+
+#[test]
+fn test_lif_rest() {
+  let mut neuron = LIFNeuron::new(0.5, Default::default());
+  assert!(spike_times(&mut neuron, 0.0, 1000).is_empty());
+  assert_eq!(neuron.v, -65.0);
+
+  // subthreshold input settles at rest + tau_m * i
+  spike_times(&mut neuron, 0.1, 2000);
+  assert!((neuron.v - -55.0).abs() < 0.1);
+}
+
+#[test]
+fn test_lif_adaptive_threshold() {
+  let mut neuron = LIFNeuron::new(0.5, LIFConfig{
+    theta_plus: 1.0,
+    tau_theta: 1000.0,
+    ..Default::default()
+  });
+
+  // every spike raises the threshold so the intervals lengthen
+  let times = spike_times(&mut neuron, 1.0, 200);
+  assert!(times.len() > 5);
+  let first = times[1] - times[0];
+  let last = times[times.len() - 1] - times[times.len() - 2];
+  assert!(last > first);
+  assert!(neuron.theta > 5.0);
+
+  // and the threshold relaxes back without input
+  let theta = neuron.theta;
+  spike_times(&mut neuron, 0.0, 1000);
+  assert!((neuron.theta - theta * (-1.0 as Float).exp()).abs() < 0.01);
+}
+
+#[test]
+fn test_lif_frozen_threshold() {
+  let config = LIFConfig{
+    theta_plus: 1.0,
+    tau_theta: 1000.0,
+    ..Default::default()
+  };
+  let mut neuron = LIFNeuron::new(0.5, config);
+  spike_times(&mut neuron, 1.0, 200);
+
+  let theta = neuron.theta;
+  let mut frozen = neuron;
+  frozen.toggle_adaptation(false);
+  let times = spike_times(&mut frozen, 1.0, 200);
+  assert_eq!(frozen.theta, theta);
+
+  // a frozen threshold fires regularly
+  let isi: Vec<usize> = times.windows(2).map(|w| w[1] - w[0]).collect();
+  assert!(isi.iter().all(|&i| i == isi[0]));
+
+  // while an adapting one keeps raising it
+  spike_times(&mut neuron, 1.0, 200);
+  assert!(neuron.theta > theta);
+}
+
+#[test]
+fn test_lif_network_freeze() {
+  let mut network: Network<LIFNeuron, STDPSynapse<ExpTrace>> = Network::new(20);
+  let a = network.add_neuron(LIFNeuron::new(0.5, LIFConfig{
+    theta_plus: 1.0,
+    ..Default::default()
+  }));
+  assert!(network.get_neuron(1).is_none());
+
+  for _ in 0..100 {
+    let mut oup: [Float; 1] = [0.0];
+    network.tick(1, &[1.0], &mut oup);
+  }
+  let theta = network.get_neuron(a).unwrap().theta;
+  assert!(theta > 0.0);
+
+  network.get_neuron_mut(a).unwrap().toggle_adaptation(false);
+  for _ in 0..100 {
+    let mut oup: [Float; 1] = [0.0];
+    network.tick(1, &[1.0], &mut oup);
+  }
+  assert_eq!(network.get_neuron(a).unwrap().theta, theta);
+}
@@ -8,7 +8,8 @@ use std::default::Default;
 
 use neural::Float;
 use neural::Network;
-use neural::izhikevich::IzhikevichNeuron;
+use neural::network::NeuralError;
+use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
 use neural::stdp::{STDPSynapse, STDPConfig};
 use neural::traces::ExpTrace;
 
@@ -66,3 +67,218 @@ fn test_network() {
     assert_eq!(oup[1], 0.0);
   }
 }
+
+fn first_spike(delay: Float) -> Float {
+  let mut network = Network::new(20);
+
+  let neuron = IzhikevichNeuron::new(1.0, Default::default());
+  let a = network.add_neuron(neuron);
+  let b = network.add_neuron(neuron);
+
+  let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: 180.0,
+    max: 180.0,
+    delay: delay,
+    ..Default::default()
+  });
+  network.add_synapse(synapse, a, b).unwrap();
+
+  let mut inp: [Float; 2] = [1000.0, 0.0];
+  loop {
+    let mut oup: [Float; 2] = [0.0, 0.0];
+    let now = network.tick(1, &mut inp, &mut oup);
+    inp[0] = 0.0;
+    if oup[1] > 0.0 {
+      return now;
+    }
+    assert!(now < 10.0);
+  }
+}
+
+#[test]
+fn test_network_fractional_delay() {
+  let t = first_spike(1.0);
+
+  // Half of the pulse lands in the arrival tick, which is not enough
+  // to reach threshold until the remainder arrives.
+  assert_eq!(first_spike(1.5), t + 1.0);
+  assert_eq!(first_spike(2.0), t + 1.0);
+  assert_eq!(first_spike(2.5), t + 2.0);
+
+  // An early arrival within the tick carries enough charge to fire.
+  assert_eq!(first_spike(2.25), t + 1.0);
+}
+
+#[test]
+fn test_network_invalid_delay() {
+  let mut network = Network::new(20);
+  let a = network.add_neuron(IzhikevichNeuron::new(1.0, Default::default()));
+  let b = network.add_neuron(IzhikevichNeuron::new(1.0, Default::default()));
+
+  for &delay in [0.0, 0.5, -1.0].iter() {
+    let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{delay: delay, ..Default::default()});
+    match network.add_synapse(synapse, a, b) {
+      Err(NeuralError::InvalidDelay) => {},
+      _ => panic!("expected an invalid delay"),
+    }
+  }
+
+  // the dendritic part is a fraction of the delay
+  for &dendritic in [-0.5, 1.5].iter() {
+    let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{delay: 2.0, dendritic: dendritic, ..Default::default()});
+    match network.add_synapse(synapse, a, b) {
+      Err(NeuralError::InvalidDelay) => {},
+      _ => panic!("expected an invalid delay"),
+    }
+  }
+  assert_eq!(network.get_synapse_count(), 0);
+  assert!(!network.is_connected(a, b));
+}
+
+// Fires `a` and then `b` after `lag` ticks and returns the resulting weight
+// change of the a -> b synapse.
+fn pairing_delta(delay: Float, dendritic: Float, lag: usize) -> Float {
+  let mut network = Network::new(20);
+
+  let neuron = IzhikevichNeuron::new(1.0, Default::default());
+  let a = network.add_neuron(neuron);
+  let b = network.add_neuron(neuron);
+
+  let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: 1.0,
+    delay: delay,
+    dendritic: dendritic,
+    ..Default::default()
+  });
+  network.add_synapse(synapse, a, b).unwrap();
+
+  for t in 0..40 {
+    let mut inp: [Float; 2] = [0.0, 0.0];
+    let mut oup: [Float; 2] = [0.0, 0.0];
+    if t == 5 {
+      inp[a] = 1000.0;
+    }
+    if t == 5 + lag {
+      inp[b] = 1000.0;
+    }
+    network.tick(1, &mut inp, &mut oup);
+  }
+
+  let mut weights: [Float; 1] = [0.0];
+  network.dump_weights(&mut weights);
+  weights[0] - 1.0
+}
+
+#[test]
+fn test_network_stdp_axonal_delay() {
+  // pre arrives before post: potentiation
+  assert!(pairing_delta(1.0, 0.0, 5) > 0.0);
+  assert!(pairing_delta(4.5, 0.0, 5) > 0.0);
+
+  // pre arrives after post: depression
+  assert!(pairing_delta(5.5, 0.0, 5) < 0.0);
+  assert!(pairing_delta(10.0, 0.0, 5) < 0.0);
+}
+
+#[test]
+fn test_network_learning_off_in_flight() {
+  // the presynaptic spike arrives after the postsynaptic one, by which
+  // time learning may have been turned off
+  for &learning in [true, false].iter() {
+    let mut network = Network::new(20);
+    let neuron = IzhikevichNeuron::new(1.0, Default::default());
+    let a = network.add_neuron(neuron);
+    let b = network.add_neuron(neuron);
+    network.add_synapse(STDPSynapse::<ExpTrace>::new(STDPConfig{
+      weight: 1.0,
+      delay: 10.0,
+      ..Default::default()
+    }), a, b).unwrap();
+
+    for t in 0..30 {
+      let mut inp: [Float; 2] = [0.0, 0.0];
+      let mut oup: [Float; 2] = [0.0, 0.0];
+      if t == 5 {
+        inp[a] = 1000.0;
+      }
+      if t == 10 {
+        inp[b] = 1000.0;
+      }
+      if t == 12 {
+        network.toggle_learning(learning);
+      }
+      network.tick(1, &mut inp, &mut oup);
+    }
+
+    let mut weights: [Float; 1] = [0.0];
+    network.dump_weights(&mut weights);
+    assert_eq!(weights[0] < 1.0, learning);
+  }
+}
+
+#[test]
+fn test_network_stdp_dendritic_delay() {
+  // The same conduction delay spent in the dendrite delays the
+  // postsynaptic spike instead, restoring potentiation.
+  assert!(pairing_delta(10.0, 1.0, 5) > 0.0);
+  assert!(pairing_delta(10.0, 0.5, 5) > 0.0);
+  assert!(pairing_delta(10.0, 0.2, 5) < 0.0);
+}
+
+// spike ticks of two neurons driven alike but starting out of phase
+fn gap_spike_times(g: Float) -> (Vec<usize>, Vec<usize>) {
+  let mut network: Network<IzhikevichNeuron, STDPSynapse<ExpTrace>> = Network::new(20);
+  let a = network.add_neuron(IzhikevichNeuron::new(0.5, Default::default()));
+  let b = network.add_neuron(IzhikevichNeuron::new(0.5, IzhikevichConfig{
+    v: -50.0,
+    ..Default::default()
+  }));
+  if g > 0.0 {
+    network.add_gap_junction(a, b, g).unwrap();
+  }
+
+  let mut times = (Vec::new(), Vec::new());
+  for t in 0..300 {
+    let mut inp: [Float; 2] = [6.0, 6.0];
+    let mut oup: [Float; 2] = [0.0, 0.0];
+    network.tick(1, &mut inp, &mut oup);
+    if oup[0] > 0.0 {
+      times.0.push(t);
+    }
+    if oup[1] > 0.0 {
+      times.1.push(t);
+    }
+  }
+  times
+}
+
+#[test]
+fn test_network_gap_junction_synchrony() {
+  let (a, b) = gap_spike_times(0.0);
+  assert!(a.last() != b.last());
+
+  let (a, b) = gap_spike_times(0.2);
+  assert!(a.len() > 3);
+  assert_eq!(a.last(), b.last());
+}
+
+#[test]
+fn test_network_gap_junction_drive() {
+  // an unstimulated neuron is pulled along by its coupled partner
+  let mut network: Network<IzhikevichNeuron, STDPSynapse<ExpTrace>> = Network::new(20);
+  let a = network.add_neuron(IzhikevichNeuron::new(0.5, Default::default()));
+  let b = network.add_neuron(IzhikevichNeuron::new(0.5, Default::default()));
+  assert!(network.add_gap_junction(a, 2, 1.0).is_err());
+  network.add_gap_junction(a, b, 1.0).unwrap();
+
+  let mut spikes = 0;
+  for _ in 0..200 {
+    let mut inp: [Float; 2] = [20.0, 0.0];
+    let mut oup: [Float; 2] = [0.0, 0.0];
+    network.tick(1, &mut inp, &mut oup);
+    if oup[1] > 0.0 {
+      spikes += 1;
+    }
+  }
+  assert!(spikes > 0);
+}
@@ -0,0 +1,272 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
+use neural::lif::LIFConfig;
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::sym::SymConfig;
+use neural::traces::ExpTrace;
+use neural::connectivity::Connection;
+use neural::neuroml::{Model, CellType, SynapseType, read_neuroml, write_neuroml};
+
+fn connection(pre: usize, post: usize, weight: Float, delay: Float) -> Connection {
+  Connection{
+    pre: pre,
+    post: post,
+    weight: weight,
+    delay: delay,
+  }
+}
+
+fn stdp() -> STDPConfig {
+  STDPConfig{
+    weight: 4.0,
+    min: 0.0,
+    max: 8.0,
+    tau_pos: 15.0,
+    scale: true,
+    ..Default::default()
+  }
+}
+
+fn model() -> Model {
+  let mut model = Model::new("net");
+  model.add_cell("rs", CellType::Izhikevich(IzhikevichConfig::regular_spiking())).unwrap();
+  model.add_cell("class1", CellType::Izhikevich(IzhikevichConfig::class1())).unwrap();
+  model.add_cell("acc", CellType::Izhikevich(IzhikevichConfig::accomodation())).unwrap();
+  model.add_cell("exc", CellType::LIF(LIFConfig::excitatory())).unwrap();
+  model.add_cell("inh", CellType::LIF(LIFConfig{tau_m: 30.0, ..LIFConfig::inhibitory()})).unwrap();
+  model.add_synapse("static", SynapseType::Static).unwrap();
+  model.add_synapse("stdp", SynapseType::STDP(stdp())).unwrap();
+  model.add_synapse("sym", SynapseType::Sym(SymConfig{tau_b: 12.5, ..Default::default()})).unwrap();
+
+  model.add_population("input", "rs", 3).unwrap();
+  model.add_population("hidden", "exc", 2).unwrap();
+  model.add_population("output", "inh", 2).unwrap();
+  model.add_projection("input_hidden", "input", "hidden", "stdp", vec![
+    connection(0, 0, 4.0, 1.0),
+    connection(2, 1, 7.5, 2.5),
+  ]).unwrap();
+  model.add_projection("hidden_output", "hidden", "output", "sym", vec![
+    connection(1, 0, -0.25, 3.0),
+  ]).unwrap();
+  model.add_projection("output_hidden", "output", "hidden", "static", Vec::new()).unwrap();
+  model
+}
+
+fn round_trip(model: &Model) -> Model {
+  let mut buffer = Vec::new();
+  write_neuroml(&mut buffer, model).unwrap();
+  read_neuroml(&buffer[..]).unwrap()
+}
+
+#[test]
+fn test_neuroml_round_trip() {
+  let model = model();
+  let read = round_trip(&model);
+
+  // the configs compare through their debug output
+  assert_eq!(read.id(), "net");
+  assert_eq!(format!("{:?}", read.cells()), format!("{:?}", model.cells()));
+  assert_eq!(format!("{:?}", read.synapses()), format!("{:?}", model.synapses()));
+  assert_eq!(format!("{:?}", read.populations()), format!("{:?}", model.populations()));
+  assert_eq!(format!("{:?}", read.projections()), format!("{:?}", model.projections()));
+
+  // unlike the presets, a custom u0 is kept as a property
+  let mut custom = Model::new("custom");
+  let config = IzhikevichConfig{u: -10.0, ..IzhikevichConfig::fast_spiking()};
+  custom.add_cell("fs", CellType::Izhikevich(config)).unwrap();
+  match round_trip(&custom).cell("fs") {
+    Some(&CellType::Izhikevich(c)) => assert_eq!(c.u, -10.0),
+    _ => panic!("expected an Izhikevich cell"),
+  }
+}
+
+#[test]
+fn test_neuroml_import() {
+  let document = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- written by hand, in other units -->
+<neuroml xmlns="http://www.neuroml.org/schema/neuroml2" id="doc">
+  <notes>Units &amp; references <b>vary</b></notes>
+  <izhikevichCell id="iz" v0="-0.07V" thresh="30mV" a="0.02" b="0.2" c="-50" d="2"/>
+  <iafCell id="iaf" C="200pF" leakConductance="0.01uS" leakReversal="-70mV" thresh="-0.05V" reset="-70mV"/>
+  <pulseGenerator id="pulse" delay="10ms" duration="100ms" amplitude="1nA"/>
+  <stdpSynapse id="plastic" weight="2" tauNeg="0.03s" continuous="true"/>
+  <staticSynapse id="fixed"/>
+  <network id="cortex">
+    <population id="a" component="iz" size="2"/>
+    <population id="b" component="iaf" type="populationList">
+      <instance id="0"/>
+      <instance id="1"/>
+      <instance id="2"/>
+    </population>
+    <projection id="ab" presynapticPopulation="a" postsynapticPopulation="b" synapse="plastic">
+      <connection id="0" preCellId="../a/1/iz" postCellId="../b/2/iaf"/>
+      <connectionWD id="1" preCellId="../a[0]" postCellId="../b[1]" weight="0.5" delay="0ms"/>
+    </projection>
+    <projection id="ba" presynapticPopulation="b" postsynapticPopulation="a" synapse="fixed">
+      <connection id="0" preCellId="../b[0]" postCellId="../a[1]"/>
+    </projection>
+    <inputList id="inputs" population="a" component="pulse"/>
+  </network>
+</neuroml>
+"#;
+  let model = read_neuroml(document.as_bytes()).unwrap();
+  assert_eq!(model.id(), "cortex");
+
+  match model.cell("iz") {
+    Some(&CellType::Izhikevich(c)) => {
+      assert_eq!(c.v, -70.0);
+      assert_eq!(c.u, 0.2 * -70.0);
+      assert_eq!(c.c, -50.0);
+      assert_eq!(c.e, 5.0);
+    },
+    _ => panic!("expected an Izhikevich cell"),
+  }
+  match model.cell("iaf") {
+    Some(&CellType::LIF(c)) => {
+      assert!((c.tau_m - 20.0).abs() < 1e-4);
+      assert_eq!(c.v_thresh, -50.0);
+      assert_eq!(c.refractory, 0.0);
+      assert!(!c.adaptive);
+    },
+    _ => panic!("expected a LIF cell"),
+  }
+  match model.synapse("plastic") {
+    Some(&SynapseType::STDP(c)) => {
+      assert_eq!(c.weight, 2.0);
+      assert_eq!(c.tau_neg, 30.0);
+      assert_eq!(c.tau_pos, 20.0);
+      assert!(c.continuous);
+    },
+    _ => panic!("expected an STDP synapse"),
+  }
+  assert!(model.cell("pulse").is_none());
+
+  assert_eq!(model.population("b").unwrap().size, 3);
+  // missing weights and delays come from the synapse type, and delays are
+  // at least a tick
+  assert_eq!(model.projection("ab").unwrap().connections, vec![
+    connection(1, 2, 2.0, 1.0),
+    connection(0, 1, 0.5, 1.0),
+  ]);
+  assert_eq!(model.projection("ba").unwrap().connections, vec![connection(0, 1, 1.0, 1.0)]);
+}
+
+fn strong() -> STDPConfig {
+  STDPConfig{max: 30.0, ..stdp()}
+}
+
+type NetworkType = Network<IzhikevichNeuron, STDPSynapse<ExpTrace>>;
+
+fn neuron(cell: &CellType) -> IzhikevichNeuron {
+  match *cell {
+    CellType::Izhikevich(config) => IzhikevichNeuron::new(0.5, config),
+    CellType::LIF(_) => panic!("expected an Izhikevich cell"),
+  }
+}
+
+// Static synapses are STDP synapses that never learn.
+fn synapse(kind: &SynapseType, c: &Connection) -> STDPSynapse<ExpTrace> {
+  let config = match *kind {
+    SynapseType::STDP(config) => config,
+    _ => STDPConfig{min: -50.0, max: 50.0, n_pos: 0.0, n_neg: 0.0, ..Default::default()},
+  };
+  STDPSynapse::new(STDPConfig{weight: c.weight, delay: c.delay, ..config})
+}
+
+fn simulate(network: &mut NetworkType, ticks: usize) -> Vec<Float> {
+  let n = network.get_neuron_count();
+  let mut inputs = vec![0.0; ticks * n];
+  for t in 0..ticks {
+    inputs[t * n] = if t % 50 < 25 { 12.0 } else { 0.0 };
+    inputs[t * n + 1] = 8.0;
+  }
+  let mut outputs = vec![0.0; n];
+  network.tick(ticks, &inputs, &mut outputs);
+  outputs
+}
+
+#[test]
+fn test_neuroml_build() {
+  let mut model = Model::new("build");
+  model.add_cell("rs", CellType::Izhikevich(IzhikevichConfig::regular_spiking())).unwrap();
+  model.add_cell("fs", CellType::Izhikevich(IzhikevichConfig::fast_spiking())).unwrap();
+  model.add_synapse("stdp", SynapseType::STDP(strong())).unwrap();
+  model.add_synapse("static", SynapseType::Static).unwrap();
+  model.add_population("exc", "rs", 3).unwrap();
+  model.add_population("inh", "fs", 1).unwrap();
+  model.add_projection("ee", "exc", "exc", "stdp", vec![
+    connection(0, 2, 20.0, 1.0),
+    connection(1, 2, 18.0, 2.0),
+  ]).unwrap();
+  model.add_projection("ei", "exc", "inh", "static", vec![connection(2, 0, 30.0, 1.0)]).unwrap();
+  model.add_projection("ie", "inh", "exc", "static", vec![connection(0, 0, -10.0, 3.0)]).unwrap();
+
+  let mut network: NetworkType = Network::new(20);
+  let ids = model.build(&mut network, neuron, synapse).unwrap();
+  assert_eq!(ids, vec![vec![0, 1, 2], vec![3]]);
+  assert_eq!(network.connections(), vec![
+    connection(0, 2, 20.0, 1.0),
+    connection(1, 2, 18.0, 2.0),
+    connection(2, 3, 30.0, 1.0),
+    connection(3, 0, -10.0, 3.0),
+  ]);
+
+  // a model read back builds a network that behaves the same
+  let mut copy: NetworkType = Network::new(20);
+  round_trip(&model).build(&mut copy, neuron, synapse).unwrap();
+  let outputs = simulate(&mut network, 1000);
+  assert!(outputs.iter().all(|&o| o > 0.0));
+  assert_eq!(simulate(&mut copy, 1000), outputs);
+
+  // learned weights are exported from the network
+  let mut trained = Model::new("trained");
+  trained.add_cell("rs", CellType::Izhikevich(IzhikevichConfig::regular_spiking())).unwrap();
+  trained.add_synapse("stdp", SynapseType::STDP(strong())).unwrap();
+  trained.add_population("exc", "rs", 3).unwrap();
+  trained.add_network_projection("ee", "exc", "exc", "stdp", &network, &ids[0], &ids[0]).unwrap();
+  let weights: Vec<Float> = network.connections().iter().take(2).map(|c| c.weight).collect();
+  assert!(weights != vec![20.0, 18.0]);
+  assert_eq!(trained.projection("ee").unwrap().connections, vec![
+    connection(0, 2, weights[0], 1.0),
+    connection(1, 2, weights[1], 2.0),
+  ]);
+  assert!(trained.add_network_projection("ee", "exc", "exc", "stdp", &network, &ids[0], &ids[0]).is_err());
+}
+
+#[test]
+fn test_neuroml_errors() {
+  let mut model = model();
+  assert!(model.add_cell("rs", CellType::Izhikevich(Default::default())).is_err());
+  assert!(model.add_synapse("rs", SynapseType::Static).is_err());
+  assert!(model.add_population("other", "missing", 1).is_err());
+  assert!(model.add_projection("p", "input", "missing", "stdp", Vec::new()).is_err());
+  assert!(model.add_projection("p", "input", "hidden", "missing", Vec::new()).is_err());
+  assert!(model.add_projection("p", "input", "hidden", "stdp", vec![connection(3, 0, 1.0, 1.0)]).is_err());
+  assert!(model.add_projection("p", "input", "hidden", "stdp", vec![connection(0, 0, 1.0, 0.5)]).is_err());
+  assert!(model.add_projection("p", "input", "hidden", "stdp", Vec::new()).is_ok());
+
+  let documents = [
+    "",
+    "<neuroml><network id=\"n\"></neuroml>",
+    "<lems/>",
+    "<neuroml><izhikevichCell id=\"iz\" v0=\"-65\" a=\"0.02\" b=\"0.2\" c=\"-65\" d=\"2\"/></neuroml>",
+    "<neuroml><izhikevichCell id=\"iz\" v0=\"-65mV\" a=\"0.02\" b=\"0.2\" c=\"-65\"/></neuroml>",
+    "<neuroml><izhikevich2007Cell id=\"iz\"/><network><population id=\"p\" component=\"iz\" size=\"1\"/></network></neuroml>",
+    "<neuroml><staticSynapse id=\"s\"/><network><population id=\"p\" component=\"s\" size=\"1\"/></network></neuroml>",
+    "<neuroml><iafCell id=\"c\" C=\"1nF\" leakConductance=\"10nS\" leakReversal=\"-65mV\" thresh=\"-50mV\" reset=\"-65mV\"/>\
+     <staticSynapse id=\"s\"/><network><population id=\"p\" component=\"c\" size=\"2\"/>\
+     <projection id=\"j\" presynapticPopulation=\"p\" postsynapticPopulation=\"p\" synapse=\"s\">\
+     <connection id=\"0\" preCellId=\"../p[0]\" postCellId=\"../q[1]\"/></projection></network></neuroml>",
+  ];
+  for document in documents.iter() {
+    assert!(read_neuroml(document.as_bytes()).is_err(), "{}", document);
+  }
+}
@@ -0,0 +1,142 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::Neuron;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::psc::{PSCNeuron, PSCConfig, Kernel};
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::traces::ExpTrace;
+
+fn psc_neuron(config: PSCConfig) -> PSCNeuron<IzhikevichNeuron> {
+  PSCNeuron::new(config, IzhikevichNeuron::new(0.5, Default::default()))
+}
+
+// synaptic current over the ticks following a single spike
+fn response(config: PSCConfig, w: Float, ticks: usize) -> Vec<Float> {
+  let mut neuron = psc_neuron(config);
+  neuron.recv_at(w, 0.0);
+  (0..ticks).map(|_| {
+    let i = neuron.current();
+    neuron.tick(1.0);
+    if neuron.threshold() > 0.0 {
+      neuron.reset();
+    }
+    i
+  }).collect()
+}
+
+#[test]
+fn test_psc_exponential() {
+  let exc = response(PSCConfig::exponential(), 2.0, 10);
+  assert_eq!(exc[0], 2.0);
+  for t in 1..exc.len() {
+    assert!((exc[t] / exc[t - 1] - (-1.0 / 5.0 as Float).exp()).abs() < 1e-4);
+  }
+
+  // inhibition decays with its own time constant
+  let inh = response(PSCConfig::exponential(), -2.0, 10);
+  assert_eq!(inh[0], -2.0);
+  for t in 1..inh.len() {
+    assert!((inh[t] / inh[t - 1] - (-1.0 / 10.0 as Float).exp()).abs() < 1e-4);
+  }
+}
+
+#[test]
+fn test_psc_dual_exponential() {
+  let psc = response(PSCConfig::dual_exponential(), 2.0, 50);
+  assert_eq!(psc[0], 0.0);
+
+  // peak normalized to the weight at t = 1.26
+  let peak = psc.iter().cloned().fold(0.0, Float::max);
+  assert!(peak <= 2.0 && peak > 1.9);
+  assert_eq!(psc[1], peak);
+  for t in 2..psc.len() {
+    assert!(psc[t] < psc[t - 1]);
+  }
+}
+
+#[test]
+fn test_psc_dual_exponential_limits() {
+  // without a rise time it is the exponential kernel
+  let psc = response(PSCConfig{
+    tau_rise_exc: 0.0,
+    ..PSCConfig::dual_exponential()
+  }, 2.0, 10);
+  assert_eq!(psc, response(PSCConfig{
+    kernel: Kernel::Exponential,
+    ..PSCConfig::dual_exponential()
+  }, 2.0, 10));
+
+  // and the alpha kernel when both time constants are equal
+  let psc = response(PSCConfig{
+    tau_rise_exc: 5.0,
+    ..PSCConfig::dual_exponential()
+  }, 2.0, 20);
+  assert!(psc.iter().all(|i| i.is_finite()));
+  assert!((psc[5] - 2.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_psc_alpha() {
+  let psc = response(PSCConfig::alpha(), 2.0, 20);
+  assert_eq!(psc[0], 0.0);
+
+  // peaks at t = tau
+  assert!((psc[2] - 2.0).abs() < 1e-4);
+  assert!(psc[1] < psc[2] && psc[3] < psc[2]);
+
+  let inh = response(PSCConfig::alpha(), -2.0, 20);
+  assert!((inh[5] + 2.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_psc_offset() {
+  let mut neuron = psc_neuron(PSCConfig::exponential());
+  neuron.recv_at(2.0, 0.25);
+  assert_eq!(neuron.current(), 1.5);
+
+  neuron.tick(1.0);
+  let expected = 1.5 * (-1.0 / 5.0 as Float).exp() + 0.5;
+  assert!((neuron.current() - expected).abs() < 1e-5);
+}
+
+// spikes of the postsynaptic neuron driven by a regularly firing input
+fn post_spikes<N: Neuron + Copy>(neuron: N, weight: Float) -> usize {
+  let mut network = Network::new(20);
+  let a = network.add_neuron(neuron);
+  let b = network.add_neuron(neuron);
+
+  let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: weight,
+    max: weight,
+    ..Default::default()
+  });
+  network.add_synapse(synapse, a, b).unwrap();
+  network.toggle_learning(false);
+
+  let mut spikes = 0;
+  for _ in 0..200 {
+    let mut inp: [Float; 2] = [10.0, 0.0];
+    let mut oup: [Float; 2] = [0.0, 0.0];
+    network.tick(1, &mut inp, &mut oup);
+    if oup[1] > 0.0 {
+      spikes += 1;
+    }
+  }
+  spikes
+}
+
+#[test]
+fn test_psc_network() {
+  // the same weight evokes more charge once spread over a kernel
+  let delta = post_spikes(IzhikevichNeuron::new(0.5, Default::default()), 10.0);
+  let psc = post_spikes(psc_neuron(PSCConfig::exponential()), 10.0);
+  assert_eq!(delta, 0);
+  assert!(psc > 0);
+}
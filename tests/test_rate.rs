@@ -0,0 +1,105 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Synapse;
+use neural::rate::{RateConfig, HebbianSynapse, OjaSynapse, BCMSynapse};
+use neural::traces::ExpTrace;
+
+// Drives a synapse with regular pre and postsynaptic spike trains over
+// ticks `from..to`. An interval of zero leaves that side silent.
+fn drive<S: Synapse>(synapse: &mut S, pre_isi: usize, post_isi: usize, from: usize, to: usize) {
+  for t in from..to {
+    if pre_isi > 0 && t % pre_isi == 0 {
+      synapse.pre_recv(t as Float);
+    }
+    if post_isi > 0 && t % post_isi == 0 {
+      synapse.post_recv(t as Float);
+    }
+  }
+}
+
+#[test]
+fn test_rate_hebbian() {
+  let mut synapse = HebbianSynapse::<ExpTrace>::new(Default::default());
+  drive(&mut synapse, 20, 0, 1, 2000);
+  assert_eq!(synapse.weight(), 1.0);
+
+  // co-active rates potentiate in proportion to their product
+  let mut slow = HebbianSynapse::<ExpTrace>::new(Default::default());
+  let mut fast = HebbianSynapse::<ExpTrace>::new(Default::default());
+  drive(&mut slow, 20, 40, 1, 2000);
+  drive(&mut fast, 20, 20, 1, 2000);
+  assert!(slow.weight() > 1.0);
+  assert!(fast.weight() - 1.0 > 1.5 * (slow.weight() - 1.0));
+}
+
+#[test]
+fn test_rate_spacing() {
+  // one pairing leaves x = y = 1 / tau_rate, and the weight change up to a
+  // later spike is the integral of their decaying product however long
+  // the gap
+  let tau: Float = 100.0;
+  for &gap in [1.0, 50.0, 300.0, 2000.0].iter() {
+    let mut synapse = HebbianSynapse::<ExpTrace>::new(Default::default());
+    synapse.pre_recv(1.0);
+    synapse.post_recv(1.0);
+    synapse.pre_recv(1.0 + gap);
+
+    let expected = 1.0 + tau / 2.0 * (1.0 - (-2.0 * gap / tau).exp()) / (tau * tau);
+    assert!((synapse.weight() - expected).abs() < 1e-6);
+  }
+
+  // so weights follow the rates whatever the phase of the spike trains
+  let mut a = HebbianSynapse::<ExpTrace>::new(Default::default());
+  let mut b = HebbianSynapse::<ExpTrace>::new(Default::default());
+  for k in 0..100 {
+    let t = 1.0 + 40.0 * k as Float;
+    a.pre_recv(t);
+    a.post_recv(t);
+    b.pre_recv(t);
+    b.post_recv(t + 20.0);
+  }
+  assert!((a.weight() - b.weight()).abs() < 0.05 * (a.weight() - 1.0));
+}
+
+#[test]
+fn test_rate_oja() {
+  // weights settle at x / y whatever they start from
+  for &w in [0.1, 1.0, 2.0].iter() {
+    let mut synapse = OjaSynapse::<ExpTrace>::new(RateConfig{
+      weight: w,
+      ..Default::default()
+    });
+    drive(&mut synapse, 20, 10, 1, 5000);
+    assert!((synapse.weight() - 0.5).abs() < 0.1);
+  }
+}
+
+#[test]
+fn test_rate_bcm() {
+  let config = RateConfig{
+    eta: 100.0,
+    ..Default::default()
+  };
+
+  // postsynaptic activity below the threshold depresses
+  let mut synapse = BCMSynapse::<ExpTrace>::new(config);
+  drive(&mut synapse, 20, 200, 1, 1000);
+  assert!(synapse.weight() < 1.0);
+
+  // above it potentiates at first
+  let mut synapse = BCMSynapse::<ExpTrace>::new(config);
+  drive(&mut synapse, 20, 20, 1, 500);
+  assert!(synapse.weight() > 1.0);
+
+  // until the threshold slides past the postsynaptic rate
+  assert!(synapse.theta() > 0.05);
+  let w = synapse.weight();
+  drive(&mut synapse, 20, 20, 500, 5000);
+  assert!(synapse.weight() < w);
+}
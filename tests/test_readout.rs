@@ -0,0 +1,217 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate rand;
+
+use std::default::Default;
+use rand::{Rng, SeedableRng, StdRng};
+
+use neural::Float;
+use neural::Network;
+use neural::izhikevich::{IzhikevichNeuron, IzhikevichConfig};
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::traces::ExpTrace;
+use neural::readout::{Readout, ReadoutConfig, StateCollector};
+
+const EXCITATORY_COUNT: usize = 160;
+const INHIBITORY_COUNT: usize = 40;
+const STEP: usize = 20;
+
+// filters spanning a few input steps and a penalty that keeps the
+// readout from fitting the reservoir noise
+fn config() -> ReadoutConfig {
+  ReadoutConfig{
+    tau: 60.0,
+    ridge: 1.0,
+  }
+}
+
+// A small version of the random recurrent network in examples/spikes.rs,
+// returning the filtered state at the end of each input step. Each value
+// of `sequence` is held for `STEP` ticks.
+fn run_reservoir(sequence: &[Float]) -> Vec<Vec<Float>> {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+  let mut network = Network::new(20);
+  network.toggle_learning(false);
+
+  let total_count = EXCITATORY_COUNT + INHIBITORY_COUNT;
+  for n in 0..total_count {
+    let r = rng.gen::<Float>();
+    let config = if n < EXCITATORY_COUNT {
+      IzhikevichConfig{
+        a: 0.02,
+        b: 0.2,
+        c: -65.0 + (15.0 * r.powi(2)),
+        d: 8.0 - (6.0 * r.powi(2)),
+        v: -65.0,
+        u: -13.0,
+        ..Default::default()
+      }
+    } else {
+      IzhikevichConfig{
+        a: 0.02 + (0.08 * r),
+        b: 0.25 - (0.05 * r),
+        c: -65.0,
+        d: 2.0,
+        v: -65.0,
+        u: -65.0 * (0.25 - (0.05 * r)),
+        ..Default::default()
+      }
+    };
+    network.add_neuron(IzhikevichNeuron::new(0.5, config));
+  }
+
+  // every neuron sees the input with its own sign and strength
+  let input_weights: Vec<Float> = (0..total_count).map(|_| 2.0 * rng.gen::<Float>() - 1.0).collect();
+
+  for n in 0..total_count {
+    for m in 0..total_count {
+      if n == m || rng.gen::<f64>() > 0.1 {
+        continue;
+      }
+
+      let weight = if n < EXCITATORY_COUNT {
+        4.0 * rng.gen::<Float>()
+      } else {
+        -8.0 * rng.gen::<Float>()
+      };
+      let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
+        weight: weight,
+        min: -100.0,
+        max: 100.0,
+        delay: 1.0,
+        ..Default::default()
+      });
+      network.add_synapse(synapse, n, m).unwrap();
+    }
+  }
+
+  let ids: Vec<usize> = (0..total_count).collect();
+  let mut collector = StateCollector::new(config(), &ids);
+
+  let mut inputs = vec![0.0; total_count];
+  let mut outputs = vec![0.0; total_count];
+  for &u in sequence.iter() {
+    for _ in 0..STEP {
+      for n in 0..total_count {
+        inputs[n] = rng.gen::<Float>() + 20.0 * input_weights[n] * u;
+        outputs[n] = 0.0;
+      }
+
+      network.tick(1, &inputs, &mut outputs);
+      collector.observe(&outputs);
+    }
+    collector.record();
+  }
+
+  collector.take_states()
+}
+
+fn random_sequence(len: usize) -> Vec<Float> {
+  let seed: &[_] = &[5, 6, 7, 8];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+  (0..len).map(|_| rng.gen::<Float>()).collect()
+}
+
+#[test]
+fn test_readout_ridge() {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+
+  // a noiseless linear map is recovered exactly
+  let states: Vec<Vec<Float>> = (0..200).map(|_| {
+    (0..5).map(|_| rng.gen::<Float>()).collect()
+  }).collect();
+  let targets: Vec<Vec<Float>> = states.iter().map(|x| {
+    vec![2.0 * x[0] - x[3] + 0.5, x[1] + x[2] + x[4] - 1.0]
+  }).collect();
+
+  let readout = Readout::fit(ReadoutConfig{
+    ridge: 1e-6,
+    ..Default::default()
+  }, &states, &targets).unwrap();
+  let expected = [2.0, 0.0, 0.0, -1.0, 0.0, 0.5, 0.0, 1.0, 1.0, 0.0, 1.0, -1.0];
+  for (w, e) in readout.weights.iter().zip(expected.iter()) {
+    assert!((w - e).abs() < 1e-3);
+  }
+  assert!(readout.error(&states, &targets).unwrap().iter().all(|&e| e < 1e-6));
+  assert!(readout.determination(&states, &targets).unwrap().iter().all(|&r| r > 0.999));
+
+  // and a heavy penalty shrinks the weights towards zero
+  let shrunk = Readout::fit(ReadoutConfig{
+    ridge: 1e4,
+    ..Default::default()
+  }, &states, &targets).unwrap();
+  assert!(shrunk.weights[0].abs() < 0.1);
+
+  // states and targets that do not pair up have no fit
+  assert!(Readout::fit(Default::default(), &states, &targets[1..]).is_none());
+  let mut ragged = states.clone();
+  ragged[10].pop();
+  assert!(Readout::fit(Default::default(), &ragged, &targets).is_none());
+
+  // nor can a readout be scored on them
+  assert!(readout.error(&[], &[]).is_none());
+  assert!(readout.error(&states, &targets[1..]).is_none());
+  assert!(readout.error(&ragged, &targets).is_none());
+  assert!(readout.determination(&[], &[]).is_none());
+}
+
+#[test]
+fn test_readout_memory_capacity() {
+  let delays = 10;
+  let sequence = random_sequence(1200);
+  let states = run_reservoir(&sequence);
+
+  // targets are the input 0..=delays steps back, the state at the end of
+  // a step already reflects that step's input
+  let washout = 100;
+  let targets: Vec<Vec<Float>> = (washout..sequence.len()).map(|t| {
+    (0..(delays + 1)).map(|d| sequence[t - d]).collect()
+  }).collect();
+  let states = &states[washout..];
+
+  let split = 800;
+  let readout = Readout::fit(config(), &states[..split], &targets[..split]).unwrap();
+  let r2 = readout.determination(&states[split..], &targets[split..]).unwrap();
+
+  let mut capacity = 0.0;
+  for r in r2.iter() {
+    capacity += *r;
+  }
+
+  // the current input is read out well, earlier ones fade
+  assert!(r2[0] > 0.5);
+  assert!(r2[1] > 0.1);
+  assert!(r2[1] > r2[delays]);
+  assert!(capacity > 1.2);
+}
+
+#[test]
+fn test_readout_delayed_xor() {
+  let sequence: Vec<Float> = random_sequence(1200).iter().map(|&u| if u < 0.5 { 0.0 } else { 1.0 }).collect();
+  let states = run_reservoir(&sequence);
+
+  // XOR of the two previous inputs, which no linear readout of the input
+  // alone could produce
+  let washout = 100;
+  let targets: Vec<Vec<Float>> = (washout..sequence.len()).map(|t| {
+    vec![if sequence[t - 1] != sequence[t - 2] { 1.0 } else { 0.0 }]
+  }).collect();
+  let states = &states[washout..];
+
+  let split = 800;
+  let readout = Readout::fit(config(), &states[..split], &targets[..split]).unwrap();
+
+  let mut correct = 0;
+  for (state, target) in states[split..].iter().zip(targets[split..].iter()) {
+    let y = if readout.predict(state)[0] > 0.5 { 1.0 } else { 0.0 };
+    if y == target[0] {
+      correct += 1;
+    }
+  }
+  let accuracy = correct as Float / (states.len() - split) as Float;
+  assert!(accuracy > 0.65);
+}
@@ -0,0 +1,111 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::Neuron;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::receptor::{self, ReceptorNeuron, ReceptorSynapse};
+use neural::receptor::neuron::mg_block;
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::traces::ExpTrace;
+
+fn receptor_neuron() -> ReceptorNeuron<IzhikevichNeuron> {
+  ReceptorNeuron::new(Default::default(), IzhikevichNeuron::new(0.5, Default::default()))
+}
+
+#[test]
+fn test_receptor_default_targets() {
+  let mut neuron = receptor_neuron();
+  neuron.recv_at(0.5, 0.0);
+  assert_eq!(neuron.conductance(receptor::AMPA), 0.5);
+  assert_eq!(neuron.conductance(receptor::NMDA), 0.5);
+  assert_eq!(neuron.conductance(receptor::GABA_A | receptor::GABA_B), 0.0);
+
+  neuron.recv_at(-0.25, 0.0);
+  assert_eq!(neuron.conductance(receptor::GABA_A), 0.25);
+  assert_eq!(neuron.conductance(receptor::GABA_B), 0.25);
+
+  neuron.recv_receptors(0.5, 0.0, receptor::NMDA);
+  assert_eq!(neuron.conductance(receptor::AMPA), 0.5);
+  assert_eq!(neuron.conductance(receptor::NMDA), 1.0);
+}
+
+#[test]
+fn test_receptor_kinetics() {
+  let mut neuron = receptor_neuron();
+  neuron.recv_receptors(0.1, 0.0, receptor::AMPA | receptor::NMDA | receptor::GABA_A | receptor::GABA_B);
+  for _ in 0..50 {
+    neuron.tick(1.0);
+    if neuron.threshold() > 0.0 {
+      neuron.reset();
+    }
+  }
+
+  // fast receptors have all but closed while slow ones remain open
+  assert!(neuron.conductance(receptor::AMPA) < 0.1 * 0.01);
+  assert!(neuron.conductance(receptor::GABA_A) < 0.1 * 0.01);
+  assert!(neuron.conductance(receptor::NMDA) > 0.1 * 0.7);
+  assert!(neuron.conductance(receptor::GABA_B) > 0.1 * 0.7);
+}
+
+#[test]
+fn test_receptor_mg_block() {
+  // NMDA is mostly blocked near rest and relieved by depolarization
+  assert!(mg_block(-70.0) < 0.05);
+  assert!(mg_block(-20.0) > 0.45);
+  assert!(mg_block(0.0) > mg_block(-20.0));
+
+  // so the same conductance depolarizes far less through NMDA than AMPA
+  let mut ampa = receptor_neuron();
+  let mut nmda = receptor_neuron();
+  ampa.recv_receptors(0.05, 0.0, receptor::AMPA);
+  nmda.recv_receptors(0.05, 0.0, receptor::NMDA);
+  ampa.tick(1.0);
+  nmda.tick(1.0);
+  let mut rest = receptor_neuron();
+  rest.tick(1.0);
+  assert!(ampa.voltage() - rest.voltage() > 10.0 * (nmda.voltage() - rest.voltage()));
+}
+
+// spikes of a tonically driven neuron inhibited by a regularly firing one
+fn inhibited_spikes(synapse: ReceptorSynapse<STDPSynapse<ExpTrace>>) -> usize {
+  let mut network = Network::new(20);
+  let a = network.add_neuron(receptor_neuron());
+  let b = network.add_neuron(receptor_neuron());
+  network.add_synapse(synapse, a, b).unwrap();
+  network.toggle_learning(false);
+
+  let mut spikes = 0;
+  for _ in 0..500 {
+    let mut inp: [Float; 2] = [10.0, 10.0];
+    let mut oup: [Float; 2] = [0.0, 0.0];
+    network.tick(1, &mut inp, &mut oup);
+    if oup[1] > 0.0 {
+      spikes += 1;
+    }
+  }
+  spikes
+}
+
+#[test]
+fn test_receptor_network() {
+  let synapse = STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: -0.05,
+    min: -0.05,
+    max: 0.0,
+    ..Default::default()
+  });
+
+  let fast = inhibited_spikes(ReceptorSynapse::new(receptor::GABA_A, synapse));
+  let slow = inhibited_spikes(ReceptorSynapse::new(receptor::GABA_B, synapse));
+  let both = inhibited_spikes(ReceptorSynapse::new(receptor::GABA_A | receptor::GABA_B, synapse));
+
+  // slow inhibition builds up across presynaptic spikes
+  assert!(slow < fast);
+  assert!(both <= slow);
+}
@@ -0,0 +1,98 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate rand;
+
+use std::default::Default;
+use rand::{Rng, SeedableRng, StdRng};
+
+use neural::Float;
+use neural::Network;
+use neural::Synapse;
+use neural::lif::{LIFNeuron, LIFConfig};
+use neural::resume::{ReSuMeSynapse, ReSuMeConfig};
+use neural::traces::ExpTrace;
+
+#[test]
+fn test_resume_window() {
+  let config = ReSuMeConfig{
+    a: 0.0,
+    ..Default::default()
+  };
+
+  // a teacher spike shortly after input potentiates
+  let mut synapse = ReSuMeSynapse::<ExpTrace>::new(config);
+  synapse.pre_recv(10.0);
+  synapse.teach(12.0);
+  assert!(synapse.weight() > 0.0);
+
+  // an actual spike there instead depresses
+  let mut synapse = ReSuMeSynapse::<ExpTrace>::new(config);
+  synapse.pre_recv(10.0);
+  synapse.post_recv(12.0);
+  assert!(synapse.weight() < 0.0);
+
+  // and when both coincide nothing changes
+  let mut synapse = ReSuMeSynapse::<ExpTrace>::new(config);
+  synapse.pre_recv(10.0);
+  synapse.teach(12.0);
+  synapse.post_recv(12.0);
+  assert!(synapse.weight().abs() < 1e-6);
+}
+
+#[test]
+fn test_resume_spike_pattern() {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+  let mut network = Network::new(20);
+
+  let input_count = 100;
+  let output = input_count;
+  let trial = 200;
+  let trials = 300;
+  let target = [40.0, 90.0, 150.0];
+
+  let neuron = LIFNeuron::new(0.5, LIFConfig{
+    tau_m: 10.0,
+    refractory: 2.0,
+    adaptive: false,
+    ..Default::default()
+  });
+  for _ in 0..input_count + 1 {
+    network.add_neuron(neuron);
+  }
+
+  for i in 0..input_count {
+    network.add_synapse(ReSuMeSynapse::<ExpTrace>::new(Default::default()), i, output).unwrap();
+  }
+
+  // a frozen random input pattern, each input firing a few times
+  let mut pattern = vec![vec![0.0; input_count + 1]; trial];
+  for i in 0..input_count {
+    for _ in 0..3 {
+      pattern[rng.gen_range::<usize>(0, trial)][i] = 100.0;
+    }
+  }
+
+  let mut spikes = Vec::new();
+  for k in 0..trials {
+    let start = (k * trial) as Float;
+    let times: Vec<Float> = target.iter().map(|t| start + t).collect();
+    network.set_target_spikes(output, &times).unwrap();
+
+    spikes.clear();
+    for t in 0..trial {
+      let mut oup = vec![0.0; input_count + 1];
+      network.tick(1, &pattern[t], &mut oup);
+      if oup[output] > 0.0 {
+        spikes.push(t as Float);
+      }
+    }
+  }
+
+  assert_eq!(spikes.len(), target.len());
+  for (s, t) in spikes.iter().zip(target.iter()) {
+    assert!((s - t).abs() <= 2.0);
+  }
+}
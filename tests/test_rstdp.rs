@@ -0,0 +1,175 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate rand;
+
+use std::default::Default;
+use rand::{Rng, SeedableRng, StdRng};
+
+use neural::Float;
+use neural::Network;
+use neural::Synapse;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::rstdp::{RSTDPSynapse, RSTDPConfig};
+use neural::traces::ExpTrace;
+
+#[test]
+fn test_rstdp_eligibility_without_dopamine() {
+  let mut synapse = RSTDPSynapse::<ExpTrace>::new(RSTDPConfig{
+    tonic: 0.0,
+    ..Default::default()
+  });
+
+  synapse.pre_recv(10.0);
+  synapse.post_recv(15.0);
+  assert!(synapse.eligibility() > 0.0);
+
+  synapse.pre_recv(1000.0);
+  assert_eq!(synapse.weight(), 1.0);
+}
+
+#[test]
+fn test_rstdp_delayed_reward() {
+  let config = RSTDPConfig{
+    tonic: 0.0,
+    ..Default::default()
+  };
+
+  // causal pairing rewarded one second later
+  let mut synapse = RSTDPSynapse::<ExpTrace>::new(config);
+  synapse.pre_recv(10.0);
+  synapse.post_recv(15.0);
+  synapse.modulate(0.01, 1015.0);
+  assert_eq!(synapse.weight(), 1.0);
+  let expected = synapse.weight_at(3000.0);
+  assert!(expected > 1.0);
+  synapse.settle(3000.0);
+  assert_eq!(synapse.weight(), expected);
+
+  // anti-causal pairing rewarded one second later
+  let mut synapse = RSTDPSynapse::<ExpTrace>::new(config);
+  synapse.post_recv(10.0);
+  synapse.pre_recv(15.0);
+  synapse.modulate(0.01, 1015.0);
+  assert!(synapse.weight_at(3000.0) < 1.0);
+
+  // rewarded long after the eligibility trace has faded
+  let mut synapse = RSTDPSynapse::<ExpTrace>::new(config);
+  synapse.pre_recv(10.0);
+  synapse.post_recv(15.0);
+  synapse.modulate(0.01, 20000.0);
+  assert!(synapse.weight_at(30000.0) - 1.0 < 1e-3);
+}
+
+// Drives neuron 0 and then neuron 1 every 100 ticks.
+fn pair(network: &mut Network<IzhikevichNeuron, RSTDPSynapse<ExpTrace>>, ticks: usize) {
+  for t in 0..ticks {
+    let inp = match t % 100 {
+      0 => [1000.0, 0.0],
+      5 => [0.0, 1000.0],
+      _ => [0.0, 0.0],
+    };
+    let mut oup = [0.0, 0.0];
+    network.tick(1, &inp, &mut oup);
+  }
+}
+
+#[test]
+fn test_rstdp_frozen_without_learning() {
+  let mut network = Network::new(20);
+  network.add_neuron(IzhikevichNeuron::new(0.5, Default::default()));
+  network.add_neuron(IzhikevichNeuron::new(0.5, Default::default()));
+  network.add_synapse(RSTDPSynapse::<ExpTrace>::new(Default::default()), 0, 1).unwrap();
+
+  pair(&mut network, 500);
+  network.modulate(0.5);
+  network.toggle_learning(false);
+  pair(&mut network, 10);
+
+  // spikes sent later no longer fold the eligibility into the weight
+  let mut before = [0.0];
+  network.dump_weights(&mut before);
+  pair(&mut network, 1000);
+  let mut after = [0.0];
+  network.dump_weights(&mut after);
+  assert_eq!(before, after);
+
+  // but do with learning
+  network.toggle_learning(true);
+  pair(&mut network, 1000);
+  network.dump_weights(&mut after);
+  assert!(after[0] != before[0]);
+}
+
+// Instrumental conditioning (Izhikevich 2007, Fig. 1) scaled down to a
+// handful of randomly firing inputs onto one neuron. Whenever the target
+// neuron fires shortly after input 0, dopamine is released 1-3s later.
+// Only the synapse from input 0 is consistently eligible when the reward
+// arrives, so over an hour it alone grows.
+#[test]
+fn test_rstdp_instrumental_conditioning() {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+
+  let input_count = 10;
+  let total_count = input_count + 1;
+  let mut network = Network::new(20);
+
+  for _ in 0..total_count {
+    network.add_neuron(IzhikevichNeuron::new(0.5, Default::default()));
+  }
+
+  let target = input_count;
+  for n in 0..input_count {
+    let synapse = RSTDPSynapse::<ExpTrace>::new(Default::default());
+    network.add_synapse(synapse, n, target).unwrap();
+  }
+
+  let input_rate = 0.001; // 1Hz
+  let target_rate = 0.005; // 5Hz
+  let mut last_input = -1000.0;
+  let mut rewards: Vec<Float> = Vec::new();
+
+  let mut inp: Vec<Float> = vec![0.0; total_count];
+  let mut oup: Vec<Float> = vec![0.0; total_count];
+
+  let duration = 3600000.0;
+  let mut now = 0.0;
+  while now < duration {
+    for n in 0..total_count {
+      let rate = if n == target { target_rate } else { input_rate };
+      inp[n] = if rng.gen::<f64>() < rate { 1000.0 } else { 0.0 };
+      oup[n] = 0.0;
+    }
+
+    now = network.tick(1, &inp, &mut oup);
+
+    if oup[0] > 0.0 {
+      last_input = now;
+    }
+
+    // target fired within 10ms of input 0 reaching it
+    if oup[target] > 0.0 && now - last_input > 1.0 && now - last_input <= 10.0 {
+      rewards.push(now + rng.gen_range::<usize>(1000, 3000) as Float);
+    }
+
+    let due = rewards.iter().filter(|&&t| t <= now).count();
+    if due > 0 {
+      rewards.retain(|&t| t > now);
+      network.modulate(0.02 * due as Float);
+    }
+  }
+
+  let mut weights = vec![0.0; input_count];
+  network.dump_weights(&mut weights);
+
+  let others = &weights[1..];
+  let mean = others.iter().fold(0.0, |sum, w| sum + w) / others.len() as Float;
+
+  assert!(weights[0] > 3.0);
+  assert!(mean < 1.0);
+  for &w in others.iter() {
+    assert!(w < 2.0);
+  }
+}
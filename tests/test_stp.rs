@@ -0,0 +1,88 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Synapse;
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::stp::{STPSynapse, STPConfig};
+use neural::sym::{SymSynapse, SymConfig};
+use neural::traces::ExpTrace;
+
+// efficacies of a regular spike train
+fn train<S: Synapse>(synapse: &mut S, count: usize, isi: Float) -> Vec<Float> {
+  (0..count).map(|i| synapse.transmit(10.0 + i as Float * isi)).collect()
+}
+
+#[test]
+fn test_stp_depressing() {
+  let mut synapse = STPSynapse::new(STPConfig::depressing(),
+    STDPSynapse::<ExpTrace>::new(STDPConfig{
+      weight: 1.0,
+      ..Default::default()
+    }));
+
+  let psr = train(&mut synapse, 10, 50.0);
+  assert!((psr[0] - 0.5).abs() < 1e-6);
+  for i in 1..psr.len() {
+    assert!(psr[i] < psr[i - 1]);
+  }
+
+  // resources recover after a long pause
+  let psr = synapse.transmit(10000.0);
+  assert!((psr - 0.5).abs() < 0.01);
+}
+
+#[test]
+fn test_stp_facilitating() {
+  let mut synapse = STPSynapse::new(STPConfig::facilitating(),
+    STDPSynapse::<ExpTrace>::new(STDPConfig{
+      weight: 1.0,
+      ..Default::default()
+    }));
+
+  let psr = train(&mut synapse, 10, 50.0);
+  assert!((psr[0] - 0.03).abs() < 1e-6);
+  for i in 1..5 {
+    assert!(psr[i] > psr[i - 1]);
+  }
+  assert!(psr[4] > 3.0 * psr[0]);
+
+  // facilitation decays after a long pause
+  let psr = synapse.transmit(10000.0);
+  assert!((psr - 0.03).abs() < 0.01);
+}
+
+#[test]
+fn test_stp_frequency_dependence() {
+  let mut slow = STPSynapse::new(STPConfig::depressing(),
+    STDPSynapse::<ExpTrace>::new(STDPConfig{
+      weight: 1.0,
+      ..Default::default()
+    }));
+  let mut fast = slow;
+
+  let slow = train(&mut slow, 10, 500.0);
+  let fast = train(&mut fast, 10, 20.0);
+  assert!(fast[9] < slow[9]);
+}
+
+#[test]
+fn test_stp_composes_with_sym() {
+  let mut synapse = STPSynapse::new(STPConfig::depressing(),
+    SymSynapse::new(SymConfig{
+      weight: 2.0,
+      ..Default::default()
+    }));
+
+  assert!((synapse.transmit(10.0) - 1.0).abs() < 1e-6);
+
+  // long-term plasticity is left to the wrapped synapse
+  synapse.pre_recv(10.0);
+  synapse.post_recv(12.0);
+  assert!(synapse.weight() > 2.0);
+  assert_eq!(synapse.weight(), synapse.synapse().weight());
+}
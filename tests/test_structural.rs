@@ -0,0 +1,195 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+
+use std::default::Default;
+
+use neural::Float;
+use neural::Network;
+use neural::Synapse;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::structural::StructuralConfig;
+use neural::stdp::{STDPSynapse, STDPConfig};
+use neural::traces::ExpTrace;
+
+fn synapse(weight: Float) -> STDPSynapse<ExpTrace> {
+  STDPSynapse::<ExpTrace>::new(STDPConfig{
+    weight: weight,
+    min: 0.0,
+    n_pos: 0.0,
+    n_neg: 0.0,
+    ..Default::default()
+  })
+}
+
+fn network(count: usize) -> Network<IzhikevichNeuron, STDPSynapse<ExpTrace>> {
+  let mut network = Network::new(20);
+  for _ in 0..count {
+    network.add_neuron(IzhikevichNeuron::new(0.5, Default::default()));
+  }
+  network
+}
+
+fn run(network: &mut Network<IzhikevichNeuron, STDPSynapse<ExpTrace>>, ticks: usize) {
+  let count = network.get_neuron_count();
+  for _ in 0..ticks {
+    let inp = vec![0.0; count];
+    let mut oup = vec![0.0; count];
+    network.tick(1, &inp, &mut oup);
+  }
+}
+
+#[test]
+fn test_structural_remove_synapse() {
+  let mut network = network(3);
+  assert_eq!(network.add_synapse(synapse(1.0), 0, 1).unwrap(), 0);
+  assert_eq!(network.add_synapse(synapse(1.0), 0, 2).unwrap(), 1);
+  assert_eq!(network.add_synapse(synapse(1.0), 1, 2).unwrap(), 2);
+
+  assert_eq!(network.remove_synapse(1).unwrap().weight(), 1.0);
+  assert!(network.remove_synapse(1).is_none());
+  assert!(!network.is_connected(0, 2));
+  assert!(network.is_connected(1, 2));
+  assert_eq!(network.get_synapse_count(), 2);
+
+  // ids stay stable
+  assert_eq!(network.add_synapse(synapse(1.0), 2, 0).unwrap(), 3);
+
+  // and removed synapses no longer transmit
+  let mut network = self::network(2);
+  let s = network.add_synapse(synapse(180.0), 0, 1).unwrap();
+  network.remove_synapse(s);
+  let mut oup: [Float; 2] = [0.0, 0.0];
+  network.tick(5, &[1000.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &mut oup);
+  assert!(oup[0] > 0.0);
+  assert_eq!(oup[1], 0.0);
+}
+
+#[test]
+fn test_structural_pruning() {
+  let mut network = network(3);
+  let weak = network.add_synapse(synapse(0.05), 0, 2).unwrap();
+  let strong = network.add_synapse(synapse(1.0), 1, 2).unwrap();
+
+  network.add_structural_plasticity(&[0, 1], &[2], StructuralConfig{
+    period: 10,
+    prune_threshold: 0.1,
+    prune_duration: 100.0,
+    growth_probability: 0.0,
+    ..Default::default()
+  }, Box::new(|_, _| synapse(1.0))).unwrap();
+
+  // weak for less than the duration
+  run(&mut network, 100);
+  assert!(network.take_structural_report().pruned.is_empty());
+
+  run(&mut network, 20);
+  let report = network.take_structural_report();
+  assert_eq!(report.pruned, vec![weak]);
+  assert!(report.created.is_empty());
+  assert!(!network.is_connected(0, 2));
+  assert!(network.is_connected(1, 2));
+  assert_eq!(network.get_synapse_count(), 1);
+  assert!(network.remove_synapse(strong).is_some());
+}
+
+#[test]
+fn test_structural_pruning_between_updates() {
+  let mut network = network(2);
+  let weak = network.add_synapse(synapse(0.05), 0, 1).unwrap();
+
+  network.add_structural_plasticity(&[0], &[1], StructuralConfig{
+    period: 50,
+    prune_threshold: 0.1,
+    prune_duration: 60.0,
+    growth_probability: 0.0,
+    ..Default::default()
+  }, Box::new(|_, _| synapse(1.0))).unwrap();
+
+  // weak since the first tick rather than the first update
+  run(&mut network, 100);
+  assert_eq!(network.take_structural_report().pruned, vec![weak]);
+}
+
+#[test]
+fn test_structural_no_immediate_regrowth() {
+  let mut network = network(2);
+  let weak = network.add_synapse(synapse(0.05), 0, 1).unwrap();
+
+  network.add_structural_plasticity(&[0], &[1], StructuralConfig{
+    period: 10,
+    prune_threshold: 0.1,
+    prune_duration: 10.0,
+    growth_probability: 1.0,
+    ..Default::default()
+  }, Box::new(|_, _| synapse(0.05))).unwrap();
+
+  // the update that prunes a pair does not grow it back
+  run(&mut network, 20);
+  let report = network.take_structural_report();
+  assert_eq!(report.pruned, vec![weak]);
+  assert!(report.created.is_empty());
+  assert!(!network.is_connected(0, 1));
+
+  // the next one may
+  run(&mut network, 10);
+  let report = network.take_structural_report();
+  assert!(report.pruned.is_empty());
+  assert_eq!(report.created.len(), 1);
+  assert!(network.is_connected(0, 1));
+}
+
+#[test]
+fn test_structural_growth() {
+  let mut network = network(4);
+  for i in 0..4 {
+    network.set_position(i, [i as Float, 0.0, 0.0]).unwrap();
+  }
+  assert!(network.set_position(4, [0.0, 0.0, 0.0]).is_err());
+
+  // every unconnected pair within reach connects on the first update
+  network.add_structural_plasticity(&[0, 1, 2, 3], &[0, 1, 2, 3], StructuralConfig{
+    period: 10,
+    growth_probability: 1.0,
+    max_distance: 1.5,
+    prune_threshold: 0.0,
+    ..Default::default()
+  }, Box::new(|sendr, recvr| synapse(1.0 + (sendr * 10 + recvr) as Float))).unwrap();
+
+  run(&mut network, 10);
+  let report = network.take_structural_report();
+  assert_eq!(report.created.len(), 6);
+  for i in 0..4 {
+    for j in 0..4 {
+      let near = i != j && (i as isize - j as isize).abs() == 1;
+      assert_eq!(network.is_connected(i, j), near);
+    }
+  }
+
+  // without creating duplicates
+  run(&mut network, 100);
+  assert!(network.take_structural_report().created.is_empty());
+  assert_eq!(network.get_synapse_count(), 6);
+
+  let mut weights = [0.0; 6];
+  network.dump_weights(&mut weights);
+  assert!(weights.iter().any(|&w| w == 1.0 + 12.0));
+}
+
+#[test]
+fn test_structural_growth_probability() {
+  let mut network = network(20);
+  let all: Vec<usize> = (0..20).collect();
+  network.add_structural_plasticity(&all, &all, StructuralConfig{
+    period: 1,
+    growth_probability: 0.01,
+    prune_threshold: 0.0,
+    ..Default::default()
+  }, Box::new(|_, _| synapse(1.0))).unwrap();
+
+  // 380 candidate pairs at 1% per update
+  run(&mut network, 10);
+  let created = network.take_structural_report().created.len();
+  assert!(created > 10 && created < 60);
+}
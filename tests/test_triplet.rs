@@ -0,0 +1,81 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate csv;
+
+use std::path::Path;
+use std::fs;
+
+use neural::Float;
+use neural::Synapse;
+use neural::triplet::{TripletSTDPSynapse, TripletSTDPConfig};
+use neural::traces::ExpTrace;
+
+// Sjöström et al. (2001) pairing protocol: 60 pre/post pairs separated by
+// `dt` ms (positive is pre before post) repeated at `rho` Hz.
+fn pairing(config: TripletSTDPConfig, rho: Float, dt: Float) -> Float {
+  let mut synapse = TripletSTDPSynapse::<ExpTrace>::new(config);
+  let period = 1000.0 / rho;
+
+  let mut now = 10.0;
+  for _ in 0..60 {
+    if dt > 0.0 {
+      synapse.pre_recv(now);
+      synapse.post_recv(now + dt);
+    } else {
+      synapse.post_recv(now);
+      synapse.pre_recv(now - dt);
+    }
+    now = now + period;
+  }
+
+  synapse.weight() - config.weight
+}
+
+fn frequency(name: &str, config: TripletSTDPConfig) {
+  let path = Path::new(&std::env::current_dir().unwrap())
+    .join("tests/results/");
+  fs::create_dir_all(&path).ok();
+
+  let filepath = path.join(&format!("{}.csv", name));
+  let mut writer = csv::Writer::from_file(filepath.as_path()).unwrap();
+
+  writer.encode(("rho", "ltp", "ltd")).ok();
+
+  let rhos = [0.1, 10.0, 20.0, 40.0, 50.0];
+  let mut ltp = Vec::new();
+  let mut ltd = Vec::new();
+  for &rho in rhos.iter() {
+    ltp.push(pairing(config, rho, 10.0));
+    ltd.push(pairing(config, rho, -10.0));
+    writer.encode((rho, ltp[ltp.len() - 1], ltd[ltd.len() - 1])).ok();
+  }
+
+  // At low frequencies post-pre pairs depress and pre-post pairs barely
+  // potentiate.
+  assert!(ltd[0] < -0.1);
+  assert!(ltp[0].abs() < 0.01);
+
+  // Pre-post potentiation grows with frequency.
+  for i in 1..rhos.len() {
+    assert!(ltp[i] > ltp[i - 1]);
+  }
+
+  // Post-pre depression turns into potentiation at high frequencies, where
+  // both orders end up potentiating.
+  assert!(ltd[2] < 0.0);
+  assert!(ltd[3] > 0.0);
+  assert!(ltd[4] > 0.5);
+  assert!(ltp[4] > 0.5);
+}
+
+#[test]
+fn test_triplet_all_to_all_frequency() {
+  frequency("triplet_all_to_all", TripletSTDPConfig::all_to_all_minimal());
+}
+
+#[test]
+fn test_triplet_nearest_spike_frequency() {
+  frequency("triplet_nearest_spike", TripletSTDPConfig::nearest_spike_minimal());
+}
@@ -0,0 +1,99 @@
+#![feature(test)]
+
+extern crate test;
+extern crate neural;
+extern crate rand;
+
+use std::default::Default;
+use rand::{Rng, SeedableRng, StdRng};
+
+use neural::Float;
+use neural::Network;
+use neural::Synapse;
+use neural::izhikevich::IzhikevichNeuron;
+use neural::conductance::ConductanceNeuron;
+use neural::vogels::{VogelsSynapse, VogelsConfig};
+use neural::traces::ExpTrace;
+
+#[test]
+fn test_vogels_symmetric() {
+  let config = VogelsConfig{
+    target_rate: 0.0,
+    ..Default::default()
+  };
+
+  // coincident spikes strengthen inhibition in either order
+  let mut synapse = VogelsSynapse::<ExpTrace>::new(config);
+  synapse.pre_recv(10.0);
+  synapse.post_recv(15.0);
+  assert!(synapse.weight() < -0.1);
+
+  let mut synapse = VogelsSynapse::<ExpTrace>::new(config);
+  synapse.post_recv(10.0);
+  synapse.pre_recv(15.0);
+  assert!(synapse.weight() < -0.1);
+}
+
+#[test]
+fn test_vogels_presynaptic_depression() {
+  // lone presynaptic spikes weaken inhibition down to zero
+  let mut synapse = VogelsSynapse::<ExpTrace>::new(Default::default());
+  for i in 0..200 {
+    synapse.pre_recv(10.0 + 1000.0 * i as Float);
+  }
+  assert_eq!(synapse.weight(), 0.0);
+}
+
+// Firing rate of a strongly driven neuron under plastic inhibitory
+// conductances from a randomly firing population, over the last quarter
+// of the run.
+fn inhibited_rate(target_rate: Float) -> Float {
+  let seed: &[_] = &[1, 2, 3, 4];
+  let mut rng: StdRng = SeedableRng::from_seed(seed);
+  let mut network = Network::new(20);
+
+  let inhibitory_count = 20;
+  let target = inhibitory_count;
+  let ticks = 100000;
+
+  let neuron = ConductanceNeuron::new(Default::default(),
+    IzhikevichNeuron::new(0.5, Default::default()));
+  for _ in 0..inhibitory_count + 1 {
+    network.add_neuron(neuron);
+  }
+
+  let synapse = VogelsSynapse::<ExpTrace>::new(VogelsConfig{
+    target_rate: target_rate,
+    ..Default::default()
+  });
+  for i in 0..inhibitory_count {
+    network.add_synapse(synapse, i, target).unwrap();
+  }
+
+  let mut spikes = 0;
+  for t in 0..ticks {
+    let mut inp = vec![0.0; inhibitory_count + 1];
+    let mut oup = vec![0.0; inhibitory_count + 1];
+    for i in 0..inhibitory_count {
+      if rng.gen::<f64>() < 0.02 {
+        inp[i] = 100.0;
+      }
+    }
+    inp[target] = 10.0 + 10.0 * rng.gen::<Float>();
+
+    network.tick(1, &inp, &mut oup);
+    if t >= ticks * 3 / 4 && oup[target] > 0.0 {
+      spikes += 1;
+    }
+  }
+
+  spikes as Float / (ticks / 4) as Float
+}
+
+#[test]
+fn test_vogels_target_rate() {
+  let low = inhibited_rate(0.005);
+  let high = inhibited_rate(0.02);
+  assert!((low - 0.005).abs() < 0.0025);
+  assert!((high - 0.02).abs() < 0.01);
+}